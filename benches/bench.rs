@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
-use nuuid::{Rng, Uuid};
+use nuuid::{state::RfcV1, Rng, Uuid, HEX_ENCODER};
 use rand_chacha::rand_core::{OsRng, RngCore};
 use uuid_::{v1::Timestamp, Builder, Uuid as Uuid_};
 
@@ -57,11 +57,33 @@ fn from_str(c: &mut Criterion) {
     group.bench_with_input("Uuid::from_str(upper hex)", input, |b, i| {
         b.iter(|| black_box(Uuid_::from_str(black_box(i))))
     });
+    group.bench_with_input("Nuuid::parse_const", input, |b, i| {
+        b.iter(|| black_box(Uuid::parse_const(black_box(i))))
+    });
+    group.finish();
+}
+
+fn eq_str(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Comparing UUIDs to strings");
+    group.throughput(Throughput::Elements(1));
+    let uuid = Uuid::new_v4();
+    let mut buf = [0; 36];
+    let input = uuid.to_str_upper(&mut buf);
+
+    group.bench_with_input("Nuuid::eq_str", input, |b, i| {
+        b.iter(|| black_box(uuid).eq_str(black_box(i)))
+    });
+    group.bench_with_input("Nuuid::parse ==", input, |b, i| {
+        b.iter(|| Uuid::parse(black_box(i)).is_ok_and(|u| u == black_box(uuid)))
+    });
     group.finish();
 }
 
 fn to_str(c: &mut Criterion) {
-    let mut group = c.benchmark_group("Constructing Strings from UUIDs (to_str)");
+    let mut group = c.benchmark_group(format!(
+        "Constructing Strings from UUIDs (to_str, {:?})",
+        HEX_ENCODER
+    ));
     group.throughput(Throughput::Elements(1));
 
     let uuid = Uuid::new_v4();
@@ -82,6 +104,58 @@ fn to_str(c: &mut Criterion) {
         });
         buf = [0u8; 36];
     });
+
+    group.bench_function("Nuuid::to_str_upper", |b| {
+        b.iter(|| {
+            uuid.to_str_upper(black_box(&mut buf));
+        });
+        buf = [0u8; 36];
+    });
+
+    group.bench_function("Uuid::hyphenated().encode_upper()", |b| {
+        b.iter(|| {
+            uuid_.hyphenated().encode_upper(black_box(&mut buf));
+        });
+        buf = [0u8; 36];
+    });
+}
+
+fn slices(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Formatting and parsing many UUIDs");
+    group.throughput(Throughput::Elements(64));
+
+    let mut rng = Rng::new();
+    let uuids: Vec<Uuid> = (0..64)
+        .map(|_| Uuid::new_v4_rng(&mut rng).erase())
+        .collect();
+    let mut buf = vec![0u8; 64 * 36];
+    let mut out = vec![Uuid::default(); 64];
+
+    group.bench_function("Nuuid::format_slice", |b| {
+        b.iter(|| {
+            black_box(Uuid::format_slice(black_box(&uuids), black_box(&mut buf)).is_ok());
+        })
+    });
+    group.bench_function("Nuuid::to_str (loop)", |b| {
+        b.iter(|| {
+            for (uuid, chunk) in black_box(&uuids).iter().zip(buf.chunks_exact_mut(36)) {
+                uuid.to_str(black_box(chunk.try_into().unwrap()));
+            }
+        })
+    });
+
+    let input = Uuid::format_slice(&uuids, &mut buf).unwrap().to_owned();
+    group.bench_with_input("Nuuid::parse_slice", &input, |b, i| {
+        b.iter(|| Uuid::parse_slice(black_box(i), black_box(&mut out)).is_ok())
+    });
+    group.bench_with_input("Nuuid::parse (loop)", &input, |b, i| {
+        b.iter(|| {
+            for (uuid, chunk) in out.iter_mut().zip(black_box(i).as_bytes().chunks_exact(36)) {
+                *uuid = Uuid::parse(std::str::from_utf8(chunk).unwrap()).unwrap();
+            }
+        })
+    });
+    group.finish();
 }
 
 fn variant(c: &mut Criterion) {
@@ -148,12 +222,40 @@ fn is_nil(c: &mut Criterion) {
     group.finish();
 }
 
+fn compare(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Comparing UUIDs");
+    group.throughput(Throughput::Elements(1));
+    let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+    let (a_, b_) = (
+        Uuid_::from_bytes(a.to_bytes()),
+        Uuid_::from_bytes(b.to_bytes()),
+    );
+
+    group.bench_function("Nuuid::eq", |bench| {
+        bench.iter(|| black_box(a) == black_box(b));
+    });
+
+    group.bench_function("Uuid::eq", |bench| {
+        bench.iter(|| black_box(a_) == black_box(b_));
+    });
+
+    group.bench_function("Nuuid::cmp", |bench| {
+        bench.iter(|| black_box(a).cmp(&black_box(b)));
+    });
+
+    group.bench_function("Uuid::cmp", |bench| {
+        bench.iter(|| black_box(a_).cmp(&black_box(b_)));
+    });
+
+    group.finish();
+}
+
 fn timestamp(c: &mut Criterion) {
     let mut group = c.benchmark_group("UUIDs timestamp");
     group.throughput(Throughput::Elements(1));
-    let time = Timestamp::from_rfc4122(12345678, 12345);
+    let time = Timestamp::from_gregorian_time(12345678, 12345);
     let bytes = *Uuid_::new_v1(time, b"654321").as_bytes();
-    let uuid = Uuid::from_bytes(bytes);
+    let uuid: Uuid<RfcV1> = Uuid::from_bytes(bytes).into_state().unwrap();
     let uuid_ = Uuid_::from_bytes(bytes);
 
     group.bench_function("Nuuid::timestamp", |b| {
@@ -186,7 +288,7 @@ fn new_v1(c: &mut Criterion) {
         b.iter(|| Uuid::new_v1(ticks, counter, node))
     });
     group.bench_function("Uuid::new_v1", |b| {
-        b.iter(|| Uuid_::new_v1(Timestamp::from_rfc4122(ticks, counter), &node))
+        b.iter(|| Uuid_::new_v1(Timestamp::from_gregorian_time(ticks, counter), &node))
     });
 }
 
@@ -195,11 +297,14 @@ criterion_group!(
     new_v4,
     new_v5,
     from_str,
+    eq_str,
     to_str,
+    slices,
     variant,
     version,
     mixed_endian,
     is_nil,
+    compare,
     timestamp,
     new_v1
 );
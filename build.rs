@@ -0,0 +1,43 @@
+//! Build script
+//!
+//! With the `capi-header` feature, this generates the C header for the
+//! `capi` module and checks it matches `include/nuuid.h`.
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    #[cfg(feature = "capi-header")]
+    header();
+}
+
+/// Generate the C header, and check or update `include/nuuid.h`.
+///
+/// The header is updated if `NUUID_UPDATE_HEADER` is set.
+#[cfg(feature = "capi-header")]
+fn header() {
+    use std::{env, fs, path::PathBuf};
+
+    let dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let path = dir.join("include").join("nuuid.h");
+    println!("cargo:rerun-if-changed=src/capi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-changed={}", path.display());
+    println!("cargo:rerun-if-env-changed=NUUID_UPDATE_HEADER");
+
+    let config = cbindgen::Config::from_file(dir.join("cbindgen.toml"))
+        .expect("cbindgen.toml should be valid");
+    // Only the `capi` module, other modules, such as `wasm`, have `extern`
+    // blocks which are not part of the C API.
+    let bindings = cbindgen::Builder::new()
+        .with_src(dir.join("src").join("capi.rs"))
+        .with_config(config)
+        .generate()
+        .expect("C header should generate");
+    let mut header = Vec::new();
+    bindings.write(&mut header);
+
+    if env::var_os("NUUID_UPDATE_HEADER").is_some() {
+        fs::write(&path, header).expect("include/nuuid.h should be writable");
+    } else if fs::read(&path).ok().as_deref() != Some(&header[..]) {
+        panic!("include/nuuid.h is out of date, rebuild with `NUUID_UPDATE_HEADER=1`");
+    }
+}
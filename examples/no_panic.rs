@@ -0,0 +1,27 @@
+//! Checks the `no-panic` guarantees hold when linked into a binary.
+//!
+//! `no-panic` only fails at link time, and only with optimizations, so build
+//! this with `cargo build --release --features no-panic --example no_panic`.
+use nuuid::Uuid;
+
+fn main() {
+    let input = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "662aa7c7-7598-4d56-8bcc-a72c30f998a2".into());
+    let uuid = match Uuid::parse(&input) {
+        Ok(uuid) => uuid,
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+    };
+
+    let mut urn = [0; 45];
+    println!("{}", uuid.to_urn_upper(&mut urn));
+
+    let mut buf = [0; 64];
+    match uuid.try_to_str_upper(&mut buf) {
+        Ok(s) => println!("{s}"),
+        Err(e) => eprintln!("{e}"),
+    }
+}
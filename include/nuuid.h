@@ -0,0 +1,120 @@
+#ifndef NUUID_H
+#define NUUID_H
+
+/* Generated by cbindgen from src/capi.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Result of a C API function.
+ */
+typedef enum NuuidStatus {
+  /**
+   * Success
+   */
+  NUUID_STATUS_OK = 0,
+  /**
+   * A required pointer argument was null.
+   */
+  NUUID_STATUS_NULL_POINTER = 1,
+  /**
+   * The input was not a valid UUID string.
+   */
+  NUUID_STATUS_PARSE_ERROR = 2,
+  /**
+   * No entropy was available to generate a random UUID.
+   */
+  NUUID_STATUS_ENTROPY_UNAVAILABLE = 3,
+} NuuidStatus;
+
+/**
+ * A UUID, as its 16 bytes.
+ */
+typedef struct NuuidUuid {
+  /**
+   * The UUID bytes, see [`Uuid::from_bytes`].
+   */
+  uint8_t bytes[16];
+} NuuidUuid;
+
+/**
+ * A nul terminated UUID string.
+ */
+typedef struct NuuidStr {
+  /**
+   * The hyphenated UUID, followed by a nul byte.
+   */
+  char buf[37];
+} NuuidStr;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Write a new random Version 4 UUID to `out`.
+ *
+ * Returns [`NuuidStatus::EntropyUnavailable`] if getrandom fails.
+ *
+ * See [`Uuid::try_new_v4`] for details.
+ *
+ * # Safety
+ *
+ * `out` must be null or valid for writes.
+ */
+enum NuuidStatus nuuid_new_v4(struct NuuidUuid *out);
+
+#if defined(NUUID_EXPERIMENTAL_UUID)
+/**
+ * Write a new Version 7 UUID to `out`, at `unix_ms` milliseconds since the
+ * unix epoch.
+ *
+ * The rest of the UUID is random.
+ * Returns [`NuuidStatus::EntropyUnavailable`] if getrandom fails.
+ *
+ * This also requires the `experimental_uuid` feature.
+ *
+ * See [`Uuid::new_v7`] for details.
+ *
+ * # Safety
+ *
+ * `out` must be null or valid for writes.
+ */
+enum NuuidStatus nuuid_v7(uint64_t unix_ms, struct NuuidUuid *out);
+#endif
+
+/**
+ * Parse the `len` bytes of `s` as a UUID, writing it to `out`.
+ *
+ * `s` does not need to be nul terminated.
+ *
+ * See [`Uuid::parse`] for the accepted formats.
+ *
+ * # Safety
+ *
+ * - `s` must be null or valid for reads of `len` bytes.
+ * - `out` must be null or valid for writes.
+ */
+enum NuuidStatus nuuid_parse(const char *s, size_t len, struct NuuidUuid *out);
+
+/**
+ * Format `uuid` as a hyphenated lowercase string into `out`.
+ *
+ * See [`Uuid::to_str`] for details.
+ *
+ * # Safety
+ *
+ * - `uuid` must be null or valid for reads.
+ * - `out` must be null or valid for writes.
+ */
+enum NuuidStatus nuuid_format(const struct NuuidUuid *uuid, struct NuuidStr *out);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* NUUID_H */
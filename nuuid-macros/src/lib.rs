@@ -0,0 +1,144 @@
+//! Procedural macros for nuuid
+//!
+//! Use these through the `macros` feature of `nuuid`, not directly.
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::quote;
+use syn::LitStr;
+
+/// Templates for each UUID format, by length.
+///
+/// `x` is a hex digit, anything else must match case insensitively.
+const TEMPLATES: &[&str] = &[
+    "xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx",
+    "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx",
+    "{xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx}",
+    "urn:uuid:xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx",
+];
+
+/// Error parsing a UUID, and the byte position of the problem, if any.
+#[derive(Debug, PartialEq)]
+struct Error {
+    msg: String,
+    position: Option<usize>,
+}
+
+impl Error {
+    fn new(msg: String, position: Option<usize>) -> Self {
+        Self { msg, position }
+    }
+}
+
+/// Parse `s` as a UUID, in any format supported by `nuuid::Uuid::parse`.
+///
+/// The messages match `nuuid::ParseUuidError`.
+fn parse(s: &str) -> Result<[u8; 16], Error> {
+    let template = TEMPLATES
+        .iter()
+        .find(|t| t.len() == s.len())
+        .ok_or_else(|| Error::new(format!("Invalid UUID length {}", s.len()), None))?
+        .as_bytes();
+
+    let mut digits = Vec::with_capacity(32);
+    for (i, c) in s.char_indices() {
+        let invalid = || Error::new(format!("Invalid character {c:?} at position {i}"), Some(i));
+        match template[i] {
+            b'x' => digits.push(c.to_digit(16).ok_or_else(invalid)? as u8),
+            b'-' if c != '-' => {
+                return Err(Error::new(format!("Expected '-' at position {i}"), Some(i)))
+            }
+            t if !c.eq_ignore_ascii_case(&(t as char)) => return Err(invalid()),
+            _ => (),
+        }
+    }
+
+    let mut bytes = [0; 16];
+    for (byte, pair) in bytes.iter_mut().zip(digits.chunks_exact(2)) {
+        *byte = (pair[0] << 4) | pair[1];
+    }
+    Ok(bytes)
+}
+
+/// The span of byte `position` in `lit`, or all of `lit` if it can't be
+/// found.
+///
+/// Spans inside literals are only available on nightly, and only for
+/// plain strings without escapes.
+fn span_of(lit: &LitStr, position: Option<usize>) -> Span {
+    let token = lit.token();
+    match position {
+        Some(i) if token.to_string() == format!("\"{}\"", lit.value()) => {
+            token.subspan(i + 1..i + 2).unwrap_or_else(|| lit.span())
+        }
+        _ => lit.span(),
+    }
+}
+
+/// Implementation of `nuuid::uuid!` with the `macros` feature.
+///
+/// Takes the path to `nuuid`, `$crate`, followed by a comma and the literal.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __uuid(input: TokenStream) -> TokenStream {
+    let mut tokens = TokenStream2::from(input).into_iter();
+    let krate: TokenStream2 = tokens
+        .by_ref()
+        .take_while(|t| !matches!(t, TokenTree::Punct(p) if p.as_char() == ','))
+        .collect();
+    let lit = match syn::parse2::<LitStr>(tokens.collect()) {
+        Ok(lit) => lit,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    match parse(&lit.value()) {
+        Ok(bytes) => quote! { #krate::Uuid::from_bytes([#(#bytes),*]) },
+        Err(e) => {
+            let msg = format!("Invalid UUID literal: {}", e.msg);
+            syn::Error::new(span_of(&lit, e.position), msg).to_compile_error()
+        }
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RAW: [u8; 16] = [
+        102, 42, 167, 199, 117, 152, 77, 86, 139, 204, 167, 44, 48, 249, 152, 162,
+    ];
+
+    #[test]
+    fn formats() {
+        for s in [
+            "662aa7c7-7598-4d56-8bcc-a72c30f998a2",
+            "662AA7C775984D568BCCA72C30F998A2",
+            "{662aa7c7-7598-4d56-8bcc-a72c30f998a2}",
+            "URN:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2",
+        ] {
+            assert_eq!(parse(s), Ok(RAW), "{s}");
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let err = |s| parse(s).unwrap_err();
+        assert_eq!(err("662aa7c7").msg, "Invalid UUID length 8");
+        assert_eq!(
+            err("662aa7c7-7598-4d56-8gcc-a72c30f998a2"),
+            Error::new("Invalid character 'g' at position 20".into(), Some(20))
+        );
+        assert_eq!(
+            err("662aa7c7+7598-4d56-8bcc-a72c30f998a2"),
+            Error::new("Expected '-' at position 8".into(), Some(8))
+        );
+        assert_eq!(
+            err("{662aa7c7-7598-4d56-8bcc-a72c30f998a2]").position,
+            Some(37)
+        );
+        assert_eq!(
+            err("662aa7c7-7598-4d56-8bcc-a72c30f998é").msg,
+            "Invalid character 'é' at position 34"
+        );
+    }
+}
@@ -0,0 +1,210 @@
+//! C API
+//!
+//! This requires the `capi` feature.
+//!
+//! The C API is a stable ABI, using only the `#[repr(C)]` types in this
+//! module, and is described by the C header `include/nuuid.h`.
+//!
+//! UUIDs are passed as [`NuuidUuid`], holding the bytes in the same order as
+//! [`Uuid::from_bytes`], and strings are written to a fixed size [`NuuidStr`].
+//! All functions return a [`NuuidStatus`].
+//!
+//! To link from C, build the crate as a static or dynamic library, such as
+//! with `cargo rustc --release --features capi --crate-type staticlib`.
+//!
+//! The header is generated by `cbindgen` with the `capi-header` feature,
+//! which fails the build if `include/nuuid.h` is out of date.
+//! Set `NUUID_UPDATE_HEADER=1` to update it instead.
+//!
+//! ```c
+//! #include "nuuid.h"
+//!
+//! NuuidUuid uuid;
+//! NuuidStr str;
+//! if (nuuid_new_v4(&uuid) == NUUID_STATUS_OK && nuuid_format(&uuid, &str) == NUUID_STATUS_OK) {
+//!     puts(str.buf);
+//! }
+//! ```
+use crate::{NuuidError, Uuid};
+use core::{ffi::c_char, slice};
+
+/// Result of a C API function.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[must_use]
+pub enum NuuidStatus {
+    /// Success
+    Ok = 0,
+
+    /// A required pointer argument was null.
+    NullPointer = 1,
+
+    /// The input was not a valid UUID string.
+    ParseError = 2,
+
+    /// No entropy was available to generate a random UUID.
+    EntropyUnavailable = 3,
+}
+
+/// A UUID, as its 16 bytes.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub struct NuuidUuid {
+    /// The UUID bytes, see [`Uuid::from_bytes`].
+    pub bytes: [u8; 16],
+}
+
+impl From<NuuidUuid> for Uuid {
+    #[inline]
+    fn from(uuid: NuuidUuid) -> Self {
+        Uuid::from_bytes(uuid.bytes)
+    }
+}
+
+impl<S: crate::state::State> From<Uuid<S>> for NuuidUuid {
+    #[inline]
+    fn from(uuid: Uuid<S>) -> Self {
+        Self {
+            bytes: uuid.to_bytes(),
+        }
+    }
+}
+
+/// A nul terminated UUID string.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct NuuidStr {
+    /// The hyphenated UUID, followed by a nul byte.
+    pub buf: [c_char; 37],
+}
+
+impl Default for NuuidStr {
+    #[inline]
+    fn default() -> Self {
+        Self { buf: [0; 37] }
+    }
+}
+
+/// Write `uuid` to `out`, if not null.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+unsafe fn write(uuid: Uuid, out: *mut NuuidUuid) -> NuuidStatus {
+    if out.is_null() {
+        return NuuidStatus::NullPointer;
+    }
+    // Safety: Caller guarantees `out` is valid
+    unsafe { out.write(uuid.into()) };
+    NuuidStatus::Ok
+}
+
+/// Write a generated `uuid` to `out`, if not null.
+///
+/// Generation never panics, since that would abort the C caller.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+unsafe fn write_new(uuid: Result<Uuid, NuuidError>, out: *mut NuuidUuid) -> NuuidStatus {
+    match uuid {
+        // Safety: Caller guarantees `out`
+        Ok(uuid) => unsafe { write(uuid, out) },
+        Err(_) => NuuidStatus::EntropyUnavailable,
+    }
+}
+
+/// Write a new random Version 4 UUID to `out`.
+///
+/// Returns [`NuuidStatus::EntropyUnavailable`] if getrandom fails.
+///
+/// See [`Uuid::try_new_v4`] for details.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn nuuid_new_v4(out: *mut NuuidUuid) -> NuuidStatus {
+    // Safety: Caller guarantees `out`
+    unsafe { write_new(Uuid::try_new_v4().map(Uuid::erase), out) }
+}
+
+/// Write a new Version 7 UUID to `out`, at `unix_ms` milliseconds since the
+/// unix epoch.
+///
+/// The rest of the UUID is random.
+/// Returns [`NuuidStatus::EntropyUnavailable`] if getrandom fails.
+///
+/// This also requires the `experimental_uuid` feature.
+///
+/// See [`Uuid::new_v7`] for details.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+#[cfg(feature = "experimental_uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+#[no_mangle]
+pub unsafe extern "C" fn nuuid_v7(unix_ms: u64, out: *mut NuuidUuid) -> NuuidStatus {
+    let uuid = crate::Rng::try_new().map(|mut rng| Uuid::new_v7_rng(unix_ms, &mut rng).erase());
+    // Safety: Caller guarantees `out`
+    unsafe { write_new(uuid, out) }
+}
+
+/// Parse the `len` bytes of `s` as a UUID, writing it to `out`.
+///
+/// `s` does not need to be nul terminated.
+///
+/// See [`Uuid::parse`] for the accepted formats.
+///
+/// # Safety
+///
+/// - `s` must be null or valid for reads of `len` bytes.
+/// - `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn nuuid_parse(
+    s: *const c_char,
+    len: usize,
+    out: *mut NuuidUuid,
+) -> NuuidStatus {
+    if s.is_null() {
+        return NuuidStatus::NullPointer;
+    }
+    // Safety: Caller guarantees `s` is valid for `len` bytes
+    let s = unsafe { slice::from_raw_parts(s.cast::<u8>(), len) };
+    match core::str::from_utf8(s)
+        .ok()
+        .and_then(|s| Uuid::parse(s).ok())
+    {
+        // Safety: Caller guarantees `out`
+        Some(uuid) => unsafe { write(uuid, out) },
+        None => NuuidStatus::ParseError,
+    }
+}
+
+/// Format `uuid` as a hyphenated lowercase string into `out`.
+///
+/// See [`Uuid::to_str`] for details.
+///
+/// # Safety
+///
+/// - `uuid` must be null or valid for reads.
+/// - `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn nuuid_format(uuid: *const NuuidUuid, out: *mut NuuidStr) -> NuuidStatus {
+    if uuid.is_null() || out.is_null() {
+        return NuuidStatus::NullPointer;
+    }
+    // Safety: Caller guarantees `uuid` is valid
+    let uuid = Uuid::from(unsafe { uuid.read() });
+
+    let mut s = [0; 36];
+    uuid.to_str(&mut s);
+    let mut str = NuuidStr::default();
+    for (c, b) in str.buf.iter_mut().zip(s) {
+        *c = b as c_char;
+    }
+    // Safety: Caller guarantees `out` is valid
+    unsafe { out.write(str) };
+    NuuidStatus::Ok
+}
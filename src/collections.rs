@@ -0,0 +1,370 @@
+//! UUID collections
+//!
+//! This requires the `alloc` feature.
+//!
+//! [`UuidSet`] and [`UuidMap`] store their UUIDs in a single sorted `Vec`,
+//! without the per-entry overhead of a `HashMap` or `BTreeMap`,
+//! and iterate in UUID order.
+//!
+//! Lookups are a binary search, and single inserts and removes are linear,
+//! so prefer building them in bulk, with [`Extend`] or [`FromIterator`].
+//!
+//! Version 7 UUIDs sort by time, so a time window can be found with
+//! [`UuidSet::range`] and [`Uuid::v7_range`](crate::Uuid::v7_range).
+//!
+//! # Example
+//!
+//! ```rust
+//! # use nuuid::{collections::UuidSet, Uuid};
+//! let a = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+//! let b = Uuid::parse("5fc9fd3a-3b57-4a5a-95a5-fee2d64b5d2e").unwrap();
+//!
+//! let set: UuidSet = [a, b, a].into_iter().collect();
+//! assert_eq!(set.len(), 2);
+//! assert!(set.contains(a));
+//! assert_eq!(set.as_slice(), [b, a]);
+//! assert_eq!(set.range(a..), [a]);
+//! ```
+use crate::{state::State, Uuid};
+use alloc::vec::Vec;
+use core::ops::{Bound, RangeBounds};
+
+/// The items in `items`, sorted by `key`, with keys in `range`.
+fn range_of<T, R: RangeBounds<Uuid>>(items: &[T], key: impl Fn(&T) -> Uuid, range: R) -> &[T] {
+    let start = match range.start_bound() {
+        Bound::Included(s) => items.partition_point(|i| key(i) < *s),
+        Bound::Excluded(s) => items.partition_point(|i| key(i) <= *s),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(e) => items.partition_point(|i| key(i) <= *e),
+        Bound::Excluded(e) => items.partition_point(|i| key(i) < *e),
+        Bound::Unbounded => items.len(),
+    };
+    &items[start..end.max(start)]
+}
+
+/// A sorted set of [`Uuid`]s.
+///
+/// See the [module](self) documentation for details.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct UuidSet {
+    uuids: Vec<Uuid>,
+}
+
+impl UuidSet {
+    /// Create an empty set.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { uuids: Vec::new() }
+    }
+
+    /// Create an empty set with space for `capacity` UUIDs.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            uuids: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Number of UUIDs in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.uuids.len()
+    }
+
+    /// Whether the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.uuids.is_empty()
+    }
+
+    /// Whether the set contains `uuid`.
+    #[inline]
+    pub fn contains<S: State>(&self, uuid: Uuid<S>) -> bool {
+        self.uuids.binary_search(&uuid.erase()).is_ok()
+    }
+
+    /// Insert `uuid`, returning whether it was not already in the set.
+    ///
+    /// This is linear in the size of the set, see [`Extend`] for bulk
+    /// inserts.
+    #[inline]
+    pub fn insert<S: State>(&mut self, uuid: Uuid<S>) -> bool {
+        match self.uuids.binary_search(&uuid.erase()) {
+            Ok(_) => false,
+            Err(i) => {
+                self.uuids.insert(i, uuid.erase());
+                true
+            }
+        }
+    }
+
+    /// Remove `uuid`, returning whether it was in the set.
+    #[inline]
+    pub fn remove<S: State>(&mut self, uuid: Uuid<S>) -> bool {
+        match self.uuids.binary_search(&uuid.erase()) {
+            Ok(i) => {
+                self.uuids.remove(i);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// The UUIDs in `range`, in order.
+    #[inline]
+    pub fn range<R: RangeBounds<Uuid>>(&self, range: R) -> &[Uuid] {
+        range_of(&self.uuids, |k| *k, range)
+    }
+
+    /// All the UUIDs, in order.
+    #[inline]
+    pub fn as_slice(&self) -> &[Uuid] {
+        &self.uuids
+    }
+
+    /// Iterate over the UUIDs, in order.
+    #[inline]
+    pub fn iter(&self) -> core::iter::Copied<core::slice::Iter<'_, Uuid>> {
+        self.uuids.iter().copied()
+    }
+
+    /// Remove all UUIDs.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.uuids.clear();
+    }
+
+    /// Shrink the capacity as much as possible.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.uuids.shrink_to_fit();
+    }
+}
+
+/// Inserts all UUIDs at once, sorting only once.
+impl<S: State> Extend<Uuid<S>> for UuidSet {
+    fn extend<T: IntoIterator<Item = Uuid<S>>>(&mut self, iter: T) {
+        self.uuids.extend(iter.into_iter().map(Uuid::erase));
+        self.uuids.sort_unstable();
+        self.uuids.dedup();
+    }
+}
+
+impl<S: State> FromIterator<Uuid<S>> for UuidSet {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = Uuid<S>>>(iter: T) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl IntoIterator for UuidSet {
+    type Item = Uuid;
+    type IntoIter = alloc::vec::IntoIter<Uuid>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.uuids.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a UuidSet {
+    type Item = Uuid;
+    type IntoIter = core::iter::Copied<core::slice::Iter<'a, Uuid>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A sorted map from [`Uuid`]s to `V`.
+///
+/// See the [module](self) documentation for details.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{collections::UuidMap, Uuid};
+/// let a = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+///
+/// let mut map = UuidMap::new();
+/// assert_eq!(map.insert(a, "first"), None);
+/// assert_eq!(map.insert(a, "second"), Some("first"));
+/// assert_eq!(map.get(a), Some(&"second"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UuidMap<V> {
+    entries: Vec<(Uuid, V)>,
+}
+
+impl<V> UuidMap<V> {
+    /// Create an empty map.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Create an empty map with space for `capacity` entries.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Number of entries in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the map is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn find(&self, uuid: Uuid) -> Result<usize, usize> {
+        self.entries.binary_search_by_key(&uuid, |(k, _)| *k)
+    }
+
+    /// Whether the map contains `uuid`.
+    #[inline]
+    pub fn contains_key<S: State>(&self, uuid: Uuid<S>) -> bool {
+        self.find(uuid.erase()).is_ok()
+    }
+
+    /// The value for `uuid`, if any.
+    #[inline]
+    pub fn get<S: State>(&self, uuid: Uuid<S>) -> Option<&V> {
+        self.find(uuid.erase()).ok().map(|i| &self.entries[i].1)
+    }
+
+    /// The value for `uuid`, if any.
+    #[inline]
+    pub fn get_mut<S: State>(&mut self, uuid: Uuid<S>) -> Option<&mut V> {
+        self.find(uuid.erase()).ok().map(|i| &mut self.entries[i].1)
+    }
+
+    /// Insert `value` for `uuid`, returning the previous value, if any.
+    ///
+    /// This is linear in the size of the map, see [`Extend`] for bulk
+    /// inserts.
+    #[inline]
+    pub fn insert<S: State>(&mut self, uuid: Uuid<S>, value: V) -> Option<V> {
+        match self.find(uuid.erase()) {
+            Ok(i) => Some(core::mem::replace(&mut self.entries[i].1, value)),
+            Err(i) => {
+                self.entries.insert(i, (uuid.erase(), value));
+                None
+            }
+        }
+    }
+
+    /// Remove `uuid`, returning its value, if any.
+    #[inline]
+    pub fn remove<S: State>(&mut self, uuid: Uuid<S>) -> Option<V> {
+        self.find(uuid.erase())
+            .ok()
+            .map(|i| self.entries.remove(i).1)
+    }
+
+    /// The entries with keys in `range`, in order.
+    #[inline]
+    pub fn range<R: RangeBounds<Uuid>>(&self, range: R) -> &[(Uuid, V)] {
+        range_of(&self.entries, |(k, _)| *k, range)
+    }
+
+    /// All the entries, in order.
+    #[inline]
+    pub fn as_slice(&self) -> &[(Uuid, V)] {
+        &self.entries
+    }
+
+    /// Iterate over the entries, in order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (Uuid, &V)> + '_ {
+        self.entries.iter().map(|(k, v)| (*k, v))
+    }
+
+    /// Iterate over the keys, in order.
+    #[inline]
+    pub fn keys(&self) -> impl Iterator<Item = Uuid> + '_ {
+        self.entries.iter().map(|(k, _)| *k)
+    }
+
+    /// Iterate over the values, in key order.
+    #[inline]
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.entries.iter().map(|(_, v)| v)
+    }
+
+    /// Iterate over the values, in key order.
+    #[inline]
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> + '_ {
+        self.entries.iter_mut().map(|(_, v)| v)
+    }
+
+    /// Remove all entries.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Shrink the capacity as much as possible.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.entries.shrink_to_fit();
+    }
+}
+
+impl<V> Default for UuidMap<V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Inserts all entries at once, sorting only once.
+///
+/// Later values replace earlier ones for the same UUID.
+impl<S: State, V> Extend<(Uuid<S>, V)> for UuidMap<V> {
+    fn extend<T: IntoIterator<Item = (Uuid<S>, V)>>(&mut self, iter: T) {
+        self.entries
+            .extend(iter.into_iter().map(|(k, v)| (k.erase(), v)));
+        // Stable, so the latest value for each UUID is last
+        self.entries.sort_by_key(|(k, _)| *k);
+        self.entries.dedup_by(|later, earlier| {
+            let dup = later.0 == earlier.0;
+            if dup {
+                core::mem::swap(later, earlier);
+            }
+            dup
+        });
+    }
+}
+
+impl<S: State, V> FromIterator<(Uuid<S>, V)> for UuidMap<V> {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = (Uuid<S>, V)>>(iter: T) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<V> IntoIterator for UuidMap<V> {
+    type Item = (Uuid, V);
+    type IntoIter = alloc::vec::IntoIter<(Uuid, V)>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
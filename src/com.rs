@@ -0,0 +1,185 @@
+//! COM identifiers
+//!
+//! Windows COM uses GUIDs in several distinct roles, which are easy to mix
+//! up. These newtypes give each role its own type, over the mixed-endian
+//! [`Guid`] representation, so they can be passed directly to COM APIs.
+//!
+//! Each formats as a braced uppercase GUID, as in the registry.
+//! [`Clsid`] and [`Iid`] can also be parsed from and formatted to their
+//! registry key path, such as `CLSID\{...}`.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use nuuid::com::Clsid;
+//! let clsid = Clsid::from_registry_path(r"CLSID\{0002DF01-0000-0000-C000-000000000046}").unwrap();
+//! assert_eq!(clsid.to_string(), "{0002DF01-0000-0000-C000-000000000046}");
+//!
+//! let mut buf = [0; 48];
+//! assert_eq!(
+//!     clsid.to_registry_path(&mut buf),
+//!     r"CLSID\{0002DF01-0000-0000-C000-000000000046}"
+//! );
+//! ```
+use crate::{state::State, Guid, ParseUuidError, Uuid};
+use core::{fmt, str::FromStr};
+
+macro_rules! com_guid {
+    ($(#[$meta:meta])* $name:ident, $key:literal) => {
+        com_guid!($(#[$meta])* $name);
+
+        impl $name {
+            /// The registry key containing these, under `HKEY_CLASSES_ROOT`.
+            pub const REGISTRY_KEY: &'static str = $key;
+
+            #[doc = concat!("Parse a registry key path, `", $key, "\\{...}`.")]
+            ///
+            /// The key name is case insensitive.
+            ///
+            /// # Errors
+            ///
+            /// - If `path` is not in the registry key, or the GUID is invalid.
+            pub fn from_registry_path(path: &str) -> Result<Self, ParseUuidError> {
+                let (key, guid) = path.split_once('\\').ok_or(ParseUuidError::invalid())?;
+                if !key.eq_ignore_ascii_case($key) || !guid.starts_with('{') {
+                    return Err(ParseUuidError::invalid());
+                }
+                Uuid::parse(guid).map(Self::new)
+            }
+
+            #[doc = concat!("Write the registry key path, `", $key, "\\{...}`, into `buf`,")]
+            /// and return it as a string.
+            pub fn to_registry_path(self, buf: &mut [u8; 48]) -> &mut str {
+                let key = $key.len();
+                buf[..key].copy_from_slice($key.as_bytes());
+                buf[key] = b'\\';
+                buf[key + 1] = b'{';
+                let _ = self.get().to_str_upper((&mut buf[key + 2..key + 38]).try_into().unwrap());
+                buf[key + 38] = b'}';
+
+                let buf = &mut buf[..key + 39];
+                debug_assert!(buf.is_ascii(), "BUG: Invalid ASCII in nuuid::com registry path");
+                // Safety: Fully initialized with ASCII
+                unsafe { core::str::from_utf8_unchecked_mut(buf) }
+            }
+        }
+    };
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+        #[repr(transparent)]
+        pub struct $name(Guid);
+
+        impl $name {
+            #[doc = concat!("Use `uuid` as a `", stringify!($name), "`.")]
+            #[inline]
+            pub const fn new<S: State>(uuid: Uuid<S>) -> Self {
+                let (data1, data2, data3, data4) = uuid.to_fields();
+                Self(Guid {
+                    data1,
+                    data2,
+                    data3,
+                    data4,
+                })
+            }
+
+            /// Return the [`Uuid`].
+            #[inline]
+            pub const fn get(self) -> Uuid {
+                let Guid {
+                    data1,
+                    data2,
+                    data3,
+                    data4,
+                } = self.0;
+                Uuid::from_fields(data1, data2, data3, data4)
+            }
+
+            /// Return the [`Guid`].
+            #[inline]
+            pub const fn guid(self) -> Guid {
+                self.0
+            }
+        }
+
+        impl<S: State> From<Uuid<S>> for $name {
+            #[inline]
+            fn from(uuid: Uuid<S>) -> Self {
+                $name::new(uuid)
+            }
+        }
+
+        impl From<$name> for Uuid {
+            #[inline]
+            fn from(id: $name) -> Self {
+                id.get()
+            }
+        }
+
+        impl From<Guid> for $name {
+            #[inline]
+            fn from(guid: Guid) -> Self {
+                Self(guid)
+            }
+        }
+
+        impl From<$name> for Guid {
+            #[inline]
+            fn from(id: $name) -> Self {
+                id.guid()
+            }
+        }
+
+        /// Parses any format supported by [`Uuid::parse`],
+        /// including braced GUIDs.
+        impl FromStr for $name {
+            type Err = ParseUuidError;
+
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Uuid::parse(s).map(Self::new)
+            }
+        }
+
+        /// Formats as a braced uppercase GUID, `{...}`.
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{{{}}}", self.get())
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, concat!(stringify!($name), "({})"), self)
+            }
+        }
+    };
+}
+
+com_guid!(
+    /// A COM class identifier, `CLSID`.
+    Clsid,
+    "CLSID"
+);
+
+com_guid!(
+    /// A COM interface identifier, `IID`.
+    Iid,
+    "Interface"
+);
+
+com_guid!(
+    /// The `CLSID` registered for a ProgID, such as `Excel.Application`.
+    ///
+    /// This is the value of `HKEY_CLASSES_ROOT\<ProgID>\CLSID`,
+    /// and refers to the class in the `CLSID` key,
+    /// see [`Clsid::to_registry_path`].
+    ProgIdGuid
+);
+
+impl From<ProgIdGuid> for Clsid {
+    #[inline]
+    fn from(id: ProgIdGuid) -> Self {
+        Self(id.0)
+    }
+}
@@ -0,0 +1,6 @@
+//! Well known UUID definitions
+//!
+//! Constants for UUIDs defined by other specifications, grouped by
+//! specification.
+pub mod efi;
+pub mod namespace;
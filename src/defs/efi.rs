@@ -0,0 +1,111 @@
+//! UEFI GUIDs
+//!
+//! Well known GUIDs from the [UEFI Specification], named without the
+//! `EFI_` prefix and `_GUID` suffix.
+//!
+//! These are [`Uuid`]s, convert them with [`Guid::from`](crate::Guid) for
+//! use in the in-memory `EFI_GUID` layout.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use nuuid::{defs::efi, Guid};
+//! let guid = Guid::from(efi::GLOBAL_VARIABLE);
+//! assert_eq!(guid.data1, 0x8BE4DF61);
+//! ```
+//!
+//! [UEFI Specification]: https://uefi.org/specifications
+use crate::Uuid;
+
+/// The vendor GUID of UEFI global variables, such as `BootOrder`.
+///
+/// `EFI_GLOBAL_VARIABLE`, 8be4df61-93ca-11d2-aa0d-00e098032b8c.
+pub const GLOBAL_VARIABLE: Uuid = Uuid::from_u128(0x8be4df61_93ca_11d2_aa0d_00e098032b8c);
+
+/// The vendor GUID of the Secure Boot `db`, `dbx`, and `dbt` variables.
+///
+/// `EFI_IMAGE_SECURITY_DATABASE_GUID`, d719b2cb-3d3a-4596-a3bc-dad00e67656f.
+pub const IMAGE_SECURITY_DATABASE: Uuid = Uuid::from_u128(0xd719b2cb_3d3a_4596_a3bc_dad00e67656f);
+
+/// A SHA-256 hash signature list entry.
+///
+/// `EFI_CERT_SHA256_GUID`, c1c41626-504c-4092-aca9-41f936934328.
+pub const CERT_SHA256: Uuid = Uuid::from_u128(0xc1c41626_504c_4092_aca9_41f936934328);
+
+/// An RSA-2048 public key signature list entry.
+///
+/// `EFI_CERT_RSA2048_GUID`, 3c5766e8-269c-4e34-aa14-ed776e85b3b6.
+pub const CERT_RSA2048: Uuid = Uuid::from_u128(0x3c5766e8_269c_4e34_aa14_ed776e85b3b6);
+
+/// An X.509 certificate signature list entry.
+///
+/// `EFI_CERT_X509_GUID`, a5c059a1-94e4-4aa7-87b5-ab155c2bf072.
+pub const CERT_X509: Uuid = Uuid::from_u128(0xa5c059a1_94e4_4aa7_87b5_ab155c2bf072);
+
+/// A PKCS#7 `WIN_CERTIFICATE_UEFI_GUID` certificate type.
+///
+/// `EFI_CERT_TYPE_PKCS7_GUID`, 4aafd29d-68df-49ee-8aa9-347d375665a7.
+pub const CERT_TYPE_PKCS7: Uuid = Uuid::from_u128(0x4aafd29d_68df_49ee_8aa9_347d375665a7);
+
+/// The Loaded Image protocol.
+///
+/// `EFI_LOADED_IMAGE_PROTOCOL_GUID`, 5b1b31a1-9562-11d2-8e3f-00a0c969723b.
+pub const LOADED_IMAGE_PROTOCOL: Uuid = Uuid::from_u128(0x5b1b31a1_9562_11d2_8e3f_00a0c969723b);
+
+/// The Device Path protocol.
+///
+/// `EFI_DEVICE_PATH_PROTOCOL_GUID`, 09576e91-6d3f-11d2-8e39-00a0c969723b.
+pub const DEVICE_PATH_PROTOCOL: Uuid = Uuid::from_u128(0x09576e91_6d3f_11d2_8e39_00a0c969723b);
+
+/// The Simple File System protocol.
+///
+/// `EFI_SIMPLE_FILE_SYSTEM_PROTOCOL_GUID`, 964e5b22-6459-11d2-8e39-00a0c969723b.
+pub const SIMPLE_FILE_SYSTEM_PROTOCOL: Uuid =
+    Uuid::from_u128(0x964e5b22_6459_11d2_8e39_00a0c969723b);
+
+/// The `EFI_FILE_INFO` information type.
+///
+/// `EFI_FILE_INFO_ID`, 09576e92-6d3f-11d2-8e39-00a0c969723b.
+pub const FILE_INFO: Uuid = Uuid::from_u128(0x09576e92_6d3f_11d2_8e39_00a0c969723b);
+
+/// The Block I/O protocol.
+///
+/// `EFI_BLOCK_IO_PROTOCOL_GUID`, 964e5b21-6459-11d2-8e39-00a0c969723b.
+pub const BLOCK_IO_PROTOCOL: Uuid = Uuid::from_u128(0x964e5b21_6459_11d2_8e39_00a0c969723b);
+
+/// The Simple Text Input protocol.
+///
+/// `EFI_SIMPLE_TEXT_INPUT_PROTOCOL_GUID`, 387477c1-69c7-11d2-8e39-00a0c969723b.
+pub const SIMPLE_TEXT_INPUT_PROTOCOL: Uuid =
+    Uuid::from_u128(0x387477c1_69c7_11d2_8e39_00a0c969723b);
+
+/// The Simple Text Output protocol.
+///
+/// `EFI_SIMPLE_TEXT_OUTPUT_PROTOCOL_GUID`, 387477c2-69c7-11d2-8e39-00a0c969723b.
+pub const SIMPLE_TEXT_OUTPUT_PROTOCOL: Uuid =
+    Uuid::from_u128(0x387477c2_69c7_11d2_8e39_00a0c969723b);
+
+/// The Graphics Output protocol.
+///
+/// `EFI_GRAPHICS_OUTPUT_PROTOCOL_GUID`, 9042a9de-23dc-4a38-96fb-7aded080516a.
+pub const GRAPHICS_OUTPUT_PROTOCOL: Uuid = Uuid::from_u128(0x9042a9de_23dc_4a38_96fb_7aded080516a);
+
+/// The Random Number Generator protocol.
+///
+/// `EFI_RNG_PROTOCOL_GUID`, 3152bca5-eade-433d-862e-c01cdc291f44.
+pub const RNG_PROTOCOL: Uuid = Uuid::from_u128(0x3152bca5_eade_433d_862e_c01cdc291f44);
+
+/// The ACPI 2.0 or later configuration table.
+///
+/// `EFI_ACPI_20_TABLE_GUID`, 8868e871-e4f1-11d3-bc22-0080c73c8881.
+pub const ACPI_20_TABLE: Uuid = Uuid::from_u128(0x8868e871_e4f1_11d3_bc22_0080c73c8881);
+
+/// The SMBIOS 3.0 configuration table.
+///
+/// `SMBIOS3_TABLE_GUID`, f2fd1544-9794-4a2c-992e-e5bbcf20e394.
+pub const SMBIOS3_TABLE: Uuid = Uuid::from_u128(0xf2fd1544_9794_4a2c_992e_e5bbcf20e394);
+
+/// The GPT partition type of the EFI System Partition.
+///
+/// `EFI_SYSTEM_PARTITION_GUID`, c12a7328-f81f-11d2-ba4b-00a0c93ec93b.
+pub const SYSTEM_PARTITION: Uuid = Uuid::from_u128(0xc12a7328_f81f_11d2_ba4b_00a0c93ec93b);
@@ -0,0 +1,82 @@
+//! Name based UUID namespaces
+//!
+//! Well known [`Namespace`](crate::Namespace)s, other than the RFC 4122 namespaces such as
+//! [`NAMESPACE_DNS`](crate::NAMESPACE_DNS), for deriving the same name
+//! based UUIDs as other software.
+//!
+//! Note that these conventions often also specify how the name is
+//! encoded, such as UTF-16, which must be followed to get the same UUIDs.
+//! Some also differ from [`Uuid::new_v5`], and have their own function,
+//! such as [`event_source_guid`].
+//!
+//! # Example
+//!
+//! ```rust
+//! # use nuuid::{defs::namespace, Uuid};
+//! let name: Vec<u8> = "Manufacturer&Family&ProductName"
+//!     .encode_utf16()
+//!     .flat_map(u16::to_le_bytes)
+//!     .collect();
+//! let chid = Uuid::new_v5(namespace::CHID, &name);
+//! ```
+use crate::{namespace, Bytes, Uuid};
+use sha1::{Digest, Sha1};
+
+namespace!(
+    /// Microsoft Computer Hardware IDs, or CHIDs, as used by Windows Update
+    /// and `fwupd` to match firmware to hardware.
+    ///
+    /// Names are the SMBIOS fields joined with `&`, encoded as UTF-16LE.
+    ///
+    /// 70ffd812-4c7f-4c7d-0000-000000000000.
+    pub CHID, "70ffd812-4c7f-4c7d-0000-000000000000"
+);
+
+namespace!(
+    /// .NET `EventSource` and TraceLogging provider names,
+    /// for the ETW provider GUID.
+    ///
+    /// .NET does not use [`Uuid::new_v5`] with this namespace,
+    /// use [`event_source_guid`] to get the same GUIDs.
+    ///
+    /// 482c2db2-c390-47c8-87f8-1a15bfc130fb.
+    pub EVENT_SOURCE, "482c2db2-c390-47c8-87f8-1a15bfc130fb"
+);
+
+/// The ETW provider GUID .NET derives for an `EventSource` or TraceLogging
+/// provider `name`, as `EventSource.GetGuid` does.
+///
+/// This is the SHA-1 of [`EVENT_SOURCE`] and the uppercase name,
+/// encoded as UTF-16BE, like [`Uuid::new_v5`], but .NET only sets the
+/// version, not the variant, and reads the hash as a mixed-endian GUID,
+/// see [`Uuid::from_bytes_me`].
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{defs::namespace::event_source_guid, Uuid};
+/// assert_eq!(
+///     event_source_guid("System.Runtime"),
+///     Uuid::parse("49592c0f-5a05-516d-aa4b-a64e02026c89").unwrap()
+/// );
+/// ```
+pub fn event_source_guid(name: &str) -> Uuid {
+    let mut hasher = Sha1::new();
+    hasher.update(EVENT_SOURCE.get().to_bytes());
+    for c in name.chars() {
+        // Like `ToUpperInvariant`, characters without a single uppercase
+        // character are unchanged.
+        let mut upper = c.to_uppercase();
+        let c = match (upper.next(), upper.next()) {
+            (Some(u), None) => u,
+            _ => c,
+        };
+        for unit in c.encode_utf16(&mut [0; 2]) {
+            hasher.update(unit.to_be_bytes());
+        }
+    }
+    let mut bytes: Bytes = hasher.finalize()[..16].try_into().unwrap();
+    // Only the version, in the high nibble of the little-endian `c` field
+    bytes[7] = (bytes[7] & 0x0F) | 0x50;
+    Uuid::from_bytes_me(bytes)
+}
@@ -0,0 +1,44 @@
+//! SMBIOS system UUID
+//!
+//! This requires the `dmi` feature.
+//!
+//! The system UUID identifies the hardware, and is set by the firmware.
+//! On Linux it is read from `/sys/class/dmi/id/product_uuid`, which is
+//! usually only readable by root.
+//!
+//! The kernel has already applied the SMBIOS byte order rules, see
+//! [`Uuid::from_smbios`] for decoding raw SMBIOS tables instead.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use nuuid::dmi;
+//! if let Some(uuid) = dmi::product_uuid().unwrap() {
+//!     println!("System UUID: {uuid}");
+//! }
+//! ```
+use crate::Uuid;
+use std::{fs, io};
+
+/// Path of the system UUID
+const PRODUCT_UUID: &str = "/sys/class/dmi/id/product_uuid";
+
+/// Read the system UUID from `/sys/class/dmi/id/product_uuid`.
+///
+/// Returns [`None`] if the firmware did not set a UUID.
+///
+/// This is only available on Linux.
+///
+/// # Errors
+///
+/// - If reading the system UUID fails, such as when not root.
+/// - [`InvalidData`](io::ErrorKind::InvalidData) if it is not a valid UUID.
+pub fn product_uuid() -> io::Result<Option<Uuid>> {
+    let s = fs::read_to_string(PRODUCT_UUID)?;
+    let uuid = Uuid::parse(s.trim()).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    // All zeros or ones mean not set or not present
+    if uuid.is_nil() || uuid.is_max() {
+        return Ok(None);
+    }
+    Ok(Some(uuid))
+}
@@ -0,0 +1,79 @@
+//! Global RNG
+//!
+//! This requires the `critical-section` feature.
+//!
+//! A global [`Rng`], protected by a [`critical_section`], for generating
+//! UUIDs in `no_std` code without thread locals, including from interrupt
+//! handlers.
+//!
+//! The global RNG starts unseeded, and must be provided with [`init`].
+//! Until then, the functions in this module return [`None`].
+//!
+//! The platform must provide a `critical-section` implementation,
+//! see the [`critical_section`] documentation for details.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use nuuid::{global, Rng, Version};
+//! # let seed = [0; 32];
+//! global::init(Rng::from_seed(seed));
+//! let uuid = global::new_v4().unwrap();
+//! assert_eq!(uuid.version(), Version::Random);
+//! ```
+#[cfg(feature = "experimental_uuid")]
+use crate::state::RfcV7;
+use crate::{state::RfcV4, Rng, Uuid};
+use core::cell::RefCell;
+use critical_section::Mutex;
+
+static RNG: Mutex<RefCell<Option<Rng>>> = Mutex::new(RefCell::new(None));
+
+/// Set the global RNG to `rng`, replacing any previous one.
+#[inline]
+pub fn init(rng: Rng) {
+    critical_section::with(|cs| {
+        RNG.borrow_ref_mut(cs).replace(rng);
+    });
+}
+
+/// Whether the global RNG has been set with [`init`].
+#[inline]
+pub fn is_init() -> bool {
+    critical_section::with(|cs| RNG.borrow_ref(cs).is_some())
+}
+
+/// Call `f` with the global RNG, inside a critical section.
+///
+/// Returns [`None`] if the global RNG has not been set with [`init`].
+///
+/// `f` should be short, since it blocks interrupts on most platforms.
+#[inline]
+pub fn with_rng<T>(f: impl FnOnce(&mut Rng) -> T) -> Option<T> {
+    critical_section::with(|cs| RNG.borrow_ref_mut(cs).as_mut().map(f))
+}
+
+/// Create a new Version 4(Random) UUID using the global RNG.
+///
+/// Returns [`None`] if the global RNG has not been set with [`init`].
+///
+/// See [`Uuid::new_v4_rng`] for details.
+#[inline]
+pub fn new_v4() -> Option<Uuid<RfcV4>> {
+    with_rng(Uuid::new_v4_rng)
+}
+
+/// Create a new Version 7 UUID at `unix_ms` milliseconds since the unix
+/// epoch, using the global RNG for the rest.
+///
+/// Returns [`None`] if the global RNG has not been set with [`init`].
+///
+/// This also requires the `experimental_uuid` feature.
+///
+/// See [`Uuid::new_v7`] for details.
+#[cfg(feature = "experimental_uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+#[inline]
+pub fn new_v7(unix_ms: u64) -> Option<Uuid<RfcV7>> {
+    with_rng(|rng| Uuid::new_v7_rng(unix_ms, rng))
+}
@@ -0,0 +1,214 @@
+//! Const hashing
+//!
+//! Const implementations of MD5 and SHA-1, for name based UUIDs at compile
+//! time, see [`Uuid::new_v3_const`](crate::Uuid::new_v3_const) and
+//! [`Uuid::new_v5_const`](crate::Uuid::new_v5_const).
+//!
+//! These are much slower than the `md-5` and `sha-1` crates,
+//! which are used at runtime.
+//!
+//! The message is always a 16 byte namespace followed by the name.
+use crate::Bytes;
+
+/// MD5 per-round shift amounts.
+const MD5_SHIFT: [u32; 64] = [
+    7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, //
+    5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20, //
+    4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, //
+    6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+];
+
+/// MD5 per-round constants, `floor(abs(sin(i + 1)) * 2^32)`.
+const MD5_K: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, //
+    0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501, //
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, //
+    0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821, //
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, //
+    0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8, //
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed, //
+    0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a, //
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c, //
+    0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70, //
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05, //
+    0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665, //
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039, //
+    0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1, //
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, //
+    0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+/// A padded message of `namespace` followed by `name`,
+/// as used by both MD5 and SHA-1.
+struct Message<'a> {
+    namespace: &'a Bytes,
+    name: &'a [u8],
+    /// Length of the padded message, a multiple of 64.
+    padded: usize,
+    /// Whether the length is big endian, for SHA-1.
+    big_endian: bool,
+}
+
+impl<'a> Message<'a> {
+    const fn new(namespace: &'a Bytes, name: &'a [u8], big_endian: bool) -> Self {
+        let len = namespace.len() + name.len();
+        Self {
+            namespace,
+            name,
+            // Room for the `0x80` byte and 8 byte length
+            padded: (len + 8) / 64 * 64 + 64,
+            big_endian,
+        }
+    }
+
+    /// Number of 64 byte blocks.
+    const fn blocks(&self) -> usize {
+        self.padded / 64
+    }
+
+    /// Byte `i` of the padded message.
+    const fn byte(&self, i: usize) -> u8 {
+        let ns = self.namespace.len();
+        let len = ns + self.name.len();
+        if i < ns {
+            self.namespace[i]
+        } else if i < len {
+            self.name[i - ns]
+        } else if i == len {
+            0x80
+        } else if i < self.padded - 8 {
+            0
+        } else {
+            let bits = (len as u64).wrapping_mul(8);
+            let n = if self.big_endian {
+                self.padded - 1 - i
+            } else {
+                i - (self.padded - 8)
+            };
+            (bits >> (n * 8)) as u8
+        }
+    }
+
+    /// Word `i` of `block`.
+    const fn word(&self, block: usize, i: usize) -> u32 {
+        let o = block * 64 + i * 4;
+        let b = [
+            self.byte(o),
+            self.byte(o + 1),
+            self.byte(o + 2),
+            self.byte(o + 3),
+        ];
+        if self.big_endian {
+            u32::from_be_bytes(b)
+        } else {
+            u32::from_le_bytes(b)
+        }
+    }
+}
+
+/// MD5 of `namespace` followed by `name`.
+pub(crate) const fn md5(namespace: &Bytes, name: &[u8]) -> [u8; 16] {
+    let msg = Message::new(namespace, name, false);
+    let mut h: [u32; 4] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+
+    let mut block = 0;
+    while block < msg.blocks() {
+        let mut m = [0; 16];
+        let mut i = 0;
+        while i < 16 {
+            m[i] = msg.word(block, i);
+            i += 1;
+        }
+
+        let [mut a, mut b, mut c, mut d] = h;
+        let mut i = 0;
+        while i < 64 {
+            let (f, g) = match i / 16 {
+                0 => ((b & c) | (!b & d), i),
+                1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+                2 => (b ^ c ^ d, (3 * i + 5) % 16),
+                _ => (c ^ (b | !d), (7 * i) % 16),
+            };
+            let f = f.wrapping_add(a).wrapping_add(MD5_K[i]).wrapping_add(m[g]);
+            a = d;
+            d = c;
+            c = b;
+            b = b.wrapping_add(f.rotate_left(MD5_SHIFT[i]));
+            i += 1;
+        }
+        h = [
+            h[0].wrapping_add(a),
+            h[1].wrapping_add(b),
+            h[2].wrapping_add(c),
+            h[3].wrapping_add(d),
+        ];
+        block += 1;
+    }
+
+    let mut out = [0; 16];
+    let mut i = 0;
+    while i < 16 {
+        out[i] = h[i / 4].to_le_bytes()[i % 4];
+        i += 1;
+    }
+    out
+}
+
+/// SHA-1 of `namespace` followed by `name`.
+pub(crate) const fn sha1(namespace: &Bytes, name: &[u8]) -> [u8; 20] {
+    let msg = Message::new(namespace, name, true);
+    let mut h: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+    let mut block = 0;
+    while block < msg.blocks() {
+        let mut w = [0u32; 80];
+        let mut i = 0;
+        while i < 16 {
+            w[i] = msg.word(block, i);
+            i += 1;
+        }
+        while i < 80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+            i += 1;
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = h;
+        let mut i = 0;
+        while i < 80 {
+            let (f, k) = match i / 20 {
+                0 => ((b & c) | (!b & d), 0x5a827999),
+                1 => (b ^ c ^ d, 0x6ed9eba1),
+                2 => ((b & c) | (b & d) | (c & d), 0x8f1bbcdc),
+                _ => (b ^ c ^ d, 0xca62c1d6),
+            };
+            let t = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(w[i]);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = t;
+            i += 1;
+        }
+        h = [
+            h[0].wrapping_add(a),
+            h[1].wrapping_add(b),
+            h[2].wrapping_add(c),
+            h[3].wrapping_add(d),
+            h[4].wrapping_add(e),
+        ];
+        block += 1;
+    }
+
+    let mut out = [0; 20];
+    let mut i = 0;
+    while i < 20 {
+        out[i] = h[i / 4].to_be_bytes()[i % 4];
+        i += 1;
+    }
+    out
+}
@@ -0,0 +1,125 @@
+//! systemd compatible machine and boot IDs
+//!
+//! This requires the `id128` feature.
+//!
+//! The machine ID uniquely identifies a host, and is read from
+//! `/etc/machine-id`, see `machine-id(5)`.
+//!
+//! The boot ID uniquely identifies the current boot of a host, and is read
+//! from `/proc/sys/kernel/random/boot_id`.
+//! It changes on every boot, which is useful for scoping sessions
+//! and crash reports.
+//!
+//! These should be considered confidential, so applications should derive
+//! their own UUID with [`machine_app_specific`] or [`boot_app_specific`]
+//! instead of using them directly. This is the same scheme as systemd's
+//! `sd_id128_get_machine_app_specific` and `sd_id128_get_boot_app_specific`,
+//! so the UUIDs match those of other programs using it.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use nuuid::{id128, Uuid};
+//! let app = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+//! let host_id = id128::machine_app_specific(app).unwrap();
+//! ```
+use crate::{
+    state::{RfcV4, State},
+    Uuid, Variant, Version,
+};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::{fs, io, path::Path};
+
+/// Path of the machine ID
+const MACHINE_ID: &str = "/etc/machine-id";
+
+/// Path of the boot ID
+const BOOT_ID: &str = "/proc/sys/kernel/random/boot_id";
+
+/// Read a UUID from the file at `path`, in any format [`Uuid::parse`]
+/// supports, with surrounding whitespace.
+///
+/// # Errors
+///
+/// - If reading `path` fails.
+/// - [`InvalidData`](io::ErrorKind::InvalidData) if it is not a valid UUID.
+fn read_id(path: &Path) -> io::Result<Uuid> {
+    let s = fs::read_to_string(path)?;
+    Uuid::parse(s.trim()).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Read the machine ID from `/etc/machine-id`.
+///
+/// # Errors
+///
+/// - If reading the machine ID fails.
+/// - [`InvalidData`](io::ErrorKind::InvalidData) if it is not a valid UUID.
+pub fn machine_id() -> io::Result<Uuid> {
+    read_id(Path::new(MACHINE_ID))
+}
+
+/// Derive an application specific UUID for this host, from the machine ID.
+///
+/// See [`app_specific`] for details.
+///
+/// # Errors
+///
+/// - See [`machine_id`].
+pub fn machine_app_specific<S: State>(app: Uuid<S>) -> io::Result<Uuid<RfcV4>> {
+    Ok(app_specific(machine_id()?, app))
+}
+
+/// Read the boot ID from `/proc/sys/kernel/random/boot_id`.
+///
+/// This is only available on Linux.
+///
+/// # Errors
+///
+/// - If reading the boot ID fails.
+/// - [`InvalidData`](io::ErrorKind::InvalidData) if it is not a valid UUID.
+pub fn boot_id() -> io::Result<Uuid> {
+    read_id(Path::new(BOOT_ID))
+}
+
+/// Derive an application specific UUID for this boot, from the boot ID.
+///
+/// See [`app_specific`] for details.
+///
+/// # Errors
+///
+/// - See [`boot_id`].
+pub fn boot_app_specific<S: State>(app: Uuid<S>) -> io::Result<Uuid<RfcV4>> {
+    Ok(app_specific(boot_id()?, app))
+}
+
+/// Derive an application specific UUID from `base`, for the application
+/// identified by `app`.
+///
+/// This is the first 16 bytes of the HMAC-SHA256 of `app` keyed with `base`,
+/// with the Version 4 version and variant set, so `base` can't be
+/// recovered from the result.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{id128, Uuid, Version};
+/// let machine = Uuid::parse("3d1219c7c4c5404aaa1f6d2a48adfda4").unwrap();
+/// let app = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+/// let uuid = id128::app_specific(machine, app);
+/// assert_eq!(uuid.version(), Version::Random);
+/// assert_eq!(uuid, Uuid::parse("3b4f1186-8f71-4716-b24d-8d57e86e1a7f").unwrap());
+/// ```
+pub fn app_specific<S: State, T: State>(base: Uuid<S>, app: Uuid<T>) -> Uuid<RfcV4> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(&base.to_bytes())
+        .expect("HMAC should accept any key length");
+    mac.update(&app.to_bytes());
+    let hash = mac.finalize().into_bytes();
+
+    let mut bytes = [0; 16];
+    bytes.copy_from_slice(&hash[..16]);
+    let mut uuid = Uuid::from_bytes(bytes);
+    uuid.set_variant(Variant::Rfc4122);
+    uuid.set_version(Version::Random);
+    uuid.cast()
+}
@@ -1,23 +1,72 @@
 //! Create and use UUID's
 #![cfg_attr(not(any(test, feature = "std")), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::{
-    convert::TryInto,
+    borrow::Borrow,
+    cmp::Ordering,
+    convert::{TryFrom, TryInto},
     fmt,
+    hash::{BuildHasherDefault, Hasher},
+    marker::PhantomData,
+    num::NonZeroU128,
     str::{from_utf8_unchecked_mut, FromStr},
 };
 
-use hex_simd::{decode_inplace, AsciiCase::Lower, Out};
+use hex_simd::decode_inplace;
 use md5::{Digest, Md5};
 #[cfg(feature = "getrandom")]
 use rand_chacha::rand_core::OsRng;
 use rand_chacha::{
-    rand_core::{RngCore, SeedableRng},
-    ChaChaRng,
+    rand_core::{CryptoRng, RngCore, SeedableRng},
+    ChaCha12Rng, ChaCha20Rng, ChaCha8Rng,
 };
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
 use sha1::Sha1;
+#[cfg(feature = "zerocopy")]
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+use crate::state::{HasTimestamp, RfcNil, RfcV1, RfcV2, RfcV3, RfcV4, RfcV5, State, Unknown};
+#[cfg(feature = "experimental_uuid")]
+use crate::state::{RfcMax, RfcV6, RfcV7, RfcV8, V8Layout};
+
+#[cfg(feature = "capi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "capi")))]
+pub mod capi;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod collections;
+pub mod com;
+pub mod defs;
+#[cfg(feature = "dmi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dmi")))]
+pub mod dmi;
+#[cfg(feature = "critical-section")]
+#[cfg_attr(docsrs, doc(cfg(feature = "critical-section")))]
+pub mod global;
+mod hash;
+#[cfg(feature = "id128")]
+#[cfg_attr(docsrs, doc(cfg(feature = "id128")))]
+pub mod id128;
+mod macros;
+#[cfg(feature = "proptest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub mod proptest;
+#[cfg(feature = "prost")]
+#[cfg_attr(docsrs, doc(cfg(feature = "prost")))]
+pub mod prost;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
+pub mod state;
+#[cfg(feature = "wasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasm")))]
+pub mod wasm;
+
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub use nuuid_macros::__uuid;
 
 const UUID_STR_LENGTH: usize = 36;
 const UUID_URN_LENGTH: usize = 45;
@@ -25,53 +74,400 @@ const UUID_BRACED_LENGTH: usize = 38;
 const UUID_SIMPLE_LENGTH: usize = 32;
 const UUID_URN: &str = "urn:uuid:";
 const UUID_URN_PREFIX: usize = UUID_URN.len();
+const UUID_RUST_PREFIX: &str = "uuid!(\"";
+const UUID_RUST_LENGTH: usize = UUID_RUST_PREFIX.len() + UUID_STR_LENGTH + 2;
+const UUID_C_LENGTH: usize = 1 + (16 * 4) + 15 + 1;
+const HEX_LOWER: &[u8; 16] = b"0123456789abcdef";
+#[cfg(feature = "hex-table")]
+const HEX_UPPER: &[u8; 16] = b"0123456789ABCDEF";
+/// The value of each ASCII hex digit, or `0xFF`.
+const HEX_DECODE: [u8; 256] = hex_decode_table();
+/// Index of each hex digit in a hyphenated UUID string.
+const UUID_STR_DIGITS: [u8; 32] = [
+    0, 1, 2, 3, 4, 5, 6, 7, //
+    9, 10, 11, 12, //
+    14, 15, 16, 17, //
+    19, 20, 21, 22, //
+    24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35,
+];
+#[cfg(feature = "hex-table")]
+const HEX_PAIRS_LOWER: [[u8; 2]; 256] = hex_pairs(HEX_LOWER);
+#[cfg(feature = "hex-table")]
+const HEX_PAIRS_UPPER: [[u8; 2]; 256] = hex_pairs(HEX_UPPER);
+const ULID_LENGTH: usize = 26;
+/// Crockford's Base32 alphabet, used by ULIDs
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
 
 /// The predefined DNS namespace, 6ba7b810-9dad-11d1-80b4-00c04fd430c8.
-pub const NAMESPACE_DNS: Uuid = Uuid::from_bytes([
+pub const NAMESPACE_DNS: Namespace = Namespace::new(Uuid::from_bytes([
     107, 167, 184, 16, 157, 173, 17, 209, 128, 180, 0, 192, 79, 212, 48, 200,
-]);
+]));
 
 /// The predefined URL namespace, 6ba7b811-9dad-11d1-80b4-00c04fd430c8.
-pub const NAMESPACE_URL: Uuid = Uuid::from_bytes([
+pub const NAMESPACE_URL: Namespace = Namespace::new(Uuid::from_bytes([
     107, 167, 184, 17, 157, 173, 17, 209, 128, 180, 0, 192, 79, 212, 48, 200,
-]);
+]));
 
 /// The predefined OID namespace, 6ba7b812-9dad-11d1-80b4-00c04fd430c8.
-pub const NAMESPACE_OID: Uuid = Uuid::from_bytes([
+pub const NAMESPACE_OID: Namespace = Namespace::new(Uuid::from_bytes([
     107, 167, 184, 18, 157, 173, 17, 209, 128, 180, 0, 192, 79, 212, 48, 200,
-]);
+]));
 
 /// The predefined X500 namespace, 6ba7b814-9dad-11d1-80b4-00c04fd430c8.
-pub const NAMESPACE_X500: Uuid = Uuid::from_bytes([
+pub const NAMESPACE_X500: Namespace = Namespace::new(Uuid::from_bytes([
     107, 167, 184, 20, 157, 173, 17, 209, 128, 180, 0, 192, 79, 212, 48, 200,
-]);
+]));
+
+/// The Bluetooth SIG Base UUID, 00000000-0000-1000-8000-00805f9b34fb.
+///
+/// 16 and 32-bit Bluetooth UUIDs are shorthand for this UUID,
+/// see [`Uuid::from_bt_short`].
+pub const BLUETOOTH_BASE_UUID: Uuid =
+    Uuid::from_bytes([0, 0, 0, 0, 0, 0, 16, 0, 128, 0, 0, 128, 95, 155, 52, 251]);
+
+/// The number of 100-nanosecond intervals between the Gregorian epoch,
+/// 1582-10-15, and the Unix epoch, 1970-01-01.
+///
+/// See [`Timestamp`] for conversions.
+pub const GREGORIAN_OFFSET: u64 = 0x01B2_1DD2_1381_4000;
 
 /// A 16 byte with the UUID.
 pub type Bytes = [u8; 16];
 
+/// The number of ChaCha rounds used by an [`Rng`].
+///
+/// More rounds give a larger security margin, fewer are faster.
+/// ChaCha8 and ChaCha12 have no known practical attacks,
+/// but ChaCha20 is the conservative default.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub enum ChaChaRounds {
+    /// ChaCha with 8 rounds.
+    ChaCha8,
+
+    /// ChaCha with 12 rounds.
+    ChaCha12,
+
+    /// ChaCha with 20 rounds.
+    #[default]
+    ChaCha20,
+}
+
+/// The ChaCha variants used by [`Rng`].
+#[derive(Debug, Clone)]
+enum ChaCha {
+    ChaCha8(ChaCha8Rng),
+    ChaCha12(ChaCha12Rng),
+    ChaCha20(ChaCha20Rng),
+}
+
+impl ChaCha {
+    #[inline]
+    fn from_seed(seed: [u8; 32], rounds: ChaChaRounds) -> Self {
+        match rounds {
+            ChaChaRounds::ChaCha8 => Self::ChaCha8(ChaCha8Rng::from_seed(seed)),
+            ChaChaRounds::ChaCha12 => Self::ChaCha12(ChaCha12Rng::from_seed(seed)),
+            ChaChaRounds::ChaCha20 => Self::ChaCha20(ChaCha20Rng::from_seed(seed)),
+        }
+    }
+}
+
+/// When an [`Rng`] reseeds itself from getrandom,
+/// see [`Rng::with_reseed_policy`].
+///
+/// If getrandom fails while reseeding, the [`Rng`] continues with its
+/// current state, and tries again on the next output.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[non_exhaustive]
+#[cfg(feature = "getrandom")]
+#[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
+pub enum ReseedPolicy {
+    /// Never reseed.
+    #[default]
+    Never,
+
+    /// Reseed after this many outputs from the same seed,
+    /// where each UUID generated is one output.
+    Outputs(u64),
+
+    /// Reseed when this much time has passed since the last seed.
+    ///
+    /// This requires the `std` feature.
+    #[cfg(any(test, feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    Interval(core::time::Duration),
+}
+
+/// Reseeding state for [`Rng`].
+#[derive(Debug, Clone, Default)]
+#[cfg(feature = "getrandom")]
+struct Reseed {
+    policy: ReseedPolicy,
+
+    /// Outputs since the last seed.
+    outputs: u64,
+
+    /// When the last seed was, only set for [`ReseedPolicy::Interval`].
+    #[cfg(any(test, feature = "std"))]
+    seeded_at: Option<std::time::Instant>,
+}
+
 /// A CSPRNG suitable for generating UUID's.
+///
+/// This is ChaCha20 by default, see [`ChaChaRounds`] for alternatives.
+///
+/// It never reseeds by default, see [`Rng::with_reseed_policy`].
+///
+/// # Security
+///
+/// With the `zeroize` feature, zeroizing an Rng erases its state and
+/// poisons it, so generating anything with it afterwards panics,
+/// instead of producing predictable output.
+/// Use [`Rng::reseed`] or a new Rng instead.
 #[derive(Debug, Clone)]
-pub struct Rng(ChaChaRng);
+pub struct Rng {
+    chacha: ChaCha,
+
+    #[cfg(feature = "getrandom")]
+    reseed: Reseed,
+
+    /// Whether the state was erased by [`zeroize::Zeroize`].
+    #[cfg(feature = "zeroize")]
+    zeroized: bool,
+}
 
 impl Rng {
     /// Create a new Rng using getrandom.
+    ///
+    /// # Panics
+    ///
+    /// - If getrandom fails, see [`Rng::try_new`].
     #[cfg(feature = "getrandom")]
     #[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
     #[inline]
     pub fn new() -> Self {
-        Self(ChaChaRng::from_rng(OsRng).unwrap())
+        Self::new_rounds(ChaChaRounds::ChaCha20)
+    }
+
+    /// Create a new ChaCha8 Rng using getrandom.
+    ///
+    /// # Panics
+    ///
+    /// - If getrandom fails, see [`Rng::try_new_rounds`].
+    #[cfg(feature = "getrandom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
+    #[inline]
+    pub fn new_chacha8() -> Self {
+        Self::new_rounds(ChaChaRounds::ChaCha8)
+    }
+
+    /// Create a new ChaCha12 Rng using getrandom.
+    ///
+    /// # Panics
+    ///
+    /// - If getrandom fails, see [`Rng::try_new_rounds`].
+    #[cfg(feature = "getrandom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
+    #[inline]
+    pub fn new_chacha12() -> Self {
+        Self::new_rounds(ChaChaRounds::ChaCha12)
+    }
+
+    /// Create a new ChaCha20 Rng using getrandom.
+    ///
+    /// This is the same as [`Rng::new`].
+    ///
+    /// # Panics
+    ///
+    /// - If getrandom fails, see [`Rng::try_new_rounds`].
+    #[cfg(feature = "getrandom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
+    #[inline]
+    pub fn new_chacha20() -> Self {
+        Self::new_rounds(ChaChaRounds::ChaCha20)
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[inline]
+    fn new_rounds(rounds: ChaChaRounds) -> Self {
+        Self::try_new_rounds(rounds).unwrap()
+    }
+
+    /// Create a new Rng using getrandom, or an error if it fails.
+    ///
+    /// # Errors
+    ///
+    /// - [`NuuidError::EntropyUnavailable`] if getrandom fails.
+    #[cfg(feature = "getrandom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
+    #[inline]
+    pub fn try_new() -> Result<Self, NuuidError> {
+        Self::try_new_rounds(ChaChaRounds::ChaCha20)
+    }
+
+    /// Create a new Rng with `rounds` using getrandom,
+    /// or an error if it fails.
+    ///
+    /// # Errors
+    ///
+    /// - [`NuuidError::EntropyUnavailable`] if getrandom fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{ChaChaRounds, Rng};
+    /// let rng = Rng::try_new_rounds(ChaChaRounds::ChaCha8).unwrap();
+    /// assert_eq!(rng.rounds(), ChaChaRounds::ChaCha8);
+    /// ```
+    #[cfg(feature = "getrandom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
+    #[inline]
+    pub fn try_new_rounds(rounds: ChaChaRounds) -> Result<Self, NuuidError> {
+        Ok(Self::from_seed_rounds(Self::os_seed()?, rounds))
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[inline]
+    fn os_seed() -> Result<[u8; 32], NuuidError> {
+        let mut seed = [0; 32];
+        OsRng
+            .try_fill_bytes(&mut seed)
+            .map_err(|_| NuuidError::EntropyUnavailable)?;
+        Ok(seed)
+    }
+
+    /// Reseed from getrandom according to `policy`.
+    ///
+    /// This can be used with any Rng, including ones created with
+    /// [`Rng::from_seed`], which are then no longer deterministic.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{ReseedPolicy, Rng};
+    /// let rng = Rng::new().with_reseed_policy(ReseedPolicy::Outputs(1_000_000));
+    /// assert_eq!(rng.reseed_policy(), ReseedPolicy::Outputs(1_000_000));
+    /// ```
+    #[cfg(feature = "getrandom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
+    #[inline]
+    pub fn with_reseed_policy(mut self, policy: ReseedPolicy) -> Self {
+        self.reseed.policy = policy;
+        self.reseed.outputs = 0;
+        #[cfg(any(test, feature = "std"))]
+        {
+            self.reseed.seeded_at = match policy {
+                ReseedPolicy::Interval(_) => Some(std::time::Instant::now()),
+                _ => None,
+            };
+        }
+        self
+    }
+
+    /// The [`ReseedPolicy`], see [`Rng::with_reseed_policy`].
+    #[cfg(feature = "getrandom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
+    #[inline]
+    pub fn reseed_policy(&self) -> ReseedPolicy {
+        self.reseed.policy
+    }
+
+    /// Reseed from getrandom now, keeping the same [`ChaChaRounds`].
+    ///
+    /// # Errors
+    ///
+    /// - [`NuuidError::EntropyUnavailable`] if getrandom fails,
+    ///   in which case the Rng is unchanged.
+    #[cfg(feature = "getrandom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
+    pub fn reseed(&mut self) -> Result<(), NuuidError> {
+        self.chacha = ChaCha::from_seed(Self::os_seed()?, self.rounds());
+        #[cfg(feature = "zeroize")]
+        {
+            self.zeroized = false;
+        }
+        self.reseed.outputs = 0;
+        #[cfg(any(test, feature = "std"))]
+        if self.reseed.seeded_at.is_some() {
+            self.reseed.seeded_at = Some(std::time::Instant::now());
+        }
+        Ok(())
+    }
+
+    /// Count an output, reseeding first if the policy requires it.
+    #[cfg(feature = "getrandom")]
+    #[inline]
+    fn reseed_if_due(&mut self) {
+        let due = match self.reseed.policy {
+            ReseedPolicy::Never => false,
+            ReseedPolicy::Outputs(n) => self.reseed.outputs >= n,
+            #[cfg(any(test, feature = "std"))]
+            ReseedPolicy::Interval(interval) => self
+                .reseed
+                .seeded_at
+                .is_some_and(|at| at.elapsed() >= interval),
+        };
+        if due {
+            // On failure, continue and try again next time
+            let _ = self.reseed();
+        }
+        self.reseed.outputs = self.reseed.outputs.saturating_add(1);
     }
 
     /// Create a new Rng from a provided seed.
     #[inline]
     pub fn from_seed(seed: [u8; 32]) -> Self {
-        Self(ChaChaRng::from_seed(seed))
+        Self::from_seed_rounds(seed, ChaChaRounds::ChaCha20)
+    }
+
+    /// Create a new Rng with `rounds` from a provided seed.
+    #[inline]
+    pub fn from_seed_rounds(seed: [u8; 32], rounds: ChaChaRounds) -> Self {
+        Self {
+            chacha: ChaCha::from_seed(seed, rounds),
+            #[cfg(feature = "getrandom")]
+            reseed: Reseed::default(),
+            #[cfg(feature = "zeroize")]
+            zeroized: false,
+        }
+    }
+
+    /// The number of ChaCha rounds used.
+    #[inline]
+    pub fn rounds(&self) -> ChaChaRounds {
+        match self.chacha {
+            ChaCha::ChaCha8(_) => ChaChaRounds::ChaCha8,
+            ChaCha::ChaCha12(_) => ChaChaRounds::ChaCha12,
+            ChaCha::ChaCha20(_) => ChaChaRounds::ChaCha20,
+        }
+    }
+
+    /// Create a new Rng seeded from `rng`.
+    ///
+    /// This is useful without the `getrandom` feature, to seed from a
+    /// platform entropy source, such as a hardware RNG.
+    #[inline]
+    pub fn from_rng<R: RngCore + CryptoRng + ?Sized>(rng: &mut R) -> Self {
+        let mut seed = [0; 32];
+        rng.fill_bytes(&mut seed);
+        Self::from_seed(seed)
     }
 
     /// Forward to rand's fill_bytes
+    ///
+    /// # Panics
+    ///
+    /// - If the Rng was zeroized.
     #[inline]
     fn fill_bytes(&mut self, dest: &mut [u8]) {
-        self.0.fill_bytes(dest)
+        #[cfg(feature = "zeroize")]
+        assert!(!self.zeroized, "nuuid::Rng used after being zeroized");
+        #[cfg(feature = "getrandom")]
+        self.reseed_if_due();
+        match &mut self.chacha {
+            ChaCha::ChaCha8(rng) => rng.fill_bytes(dest),
+            ChaCha::ChaCha12(rng) => rng.fill_bytes(dest),
+            ChaCha::ChaCha20(rng) => rng.fill_bytes(dest),
+        }
     }
 }
 
@@ -84,8 +480,36 @@ impl Default for Rng {
     }
 }
 
+/// Overwrites the internal state with zeros, and poisons the Rng,
+/// see the [`Rng`] security notes.
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl zeroize::Zeroize for Rng {
+    fn zeroize(&mut self) {
+        let zero = ChaCha::from_seed([0; 32], self.rounds());
+        // Safety: `self.chacha` is valid for writes and aligned, and the old state
+        // needs no drop.
+        unsafe { core::ptr::write_volatile(&mut self.chacha, zero) };
+        self.zeroized = true;
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl Drop for Rng {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl zeroize::ZeroizeOnDrop for Rng {}
+
 /// UUID Variants
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 #[non_exhaustive]
 pub enum Variant {
     /// Reserved for NCS backward compatibility.
@@ -113,7 +537,7 @@ impl fmt::Display for Variant {
 }
 
 /// UUID Version
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 #[non_exhaustive]
 pub enum Version {
     /// Special case for the nil UUID.
@@ -175,1063 +599,7043 @@ impl fmt::Display for Version {
     }
 }
 
-/// Error parsing UUID
-#[derive(Debug)]
-pub struct ParseUuidError;
-
-impl fmt::Display for ParseUuidError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "ParseUuidError")
-    }
-}
-
-#[cfg(any(test, feature = "std"))]
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-impl std::error::Error for ParseUuidError {}
-
-/// Universally Unique Identifier, or UUID.
+/// A decoded UUID timestamp
 ///
-/// This type is `repr(transparent)` and guaranteed to have the same layout
-/// as `[u8; 16]`.
+/// This knows which version it came from, and so which epoch and
+/// precision it is in.
 ///
-/// The various methods on `Uuid` assume each field
-/// is laid out Most Significant Byte First/MSB/Big-Endian/Network Endian.
+/// - [`Version::Time`] and [`Version::Database`] timestamps are a count of
+///   100-nanosecond intervals since the Gregorian epoch, 1582-10-15.
+/// - [`Version::UnixTime`] timestamps are a count of milliseconds since the
+///   Unix epoch, 1970-01-01.
 ///
-/// This type is also `serde(transparent)`, when serde is enabled.
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
-#[repr(transparent)]
-pub struct Uuid(Bytes);
+/// See [`Uuid::timestamp`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Timestamp {
+    version: Version,
+    ticks: u64,
+}
 
-impl Uuid {
-    /// Set the UUID Version.
+impl Timestamp {
+    /// Create a [`Version::Time`] timestamp from 100-nanosecond intervals
+    /// since the Gregorian epoch.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Timestamp;
+    /// let time = Timestamp::from_gregorian_100ns(138788330336896890);
+    /// assert_eq!(time.to_unix(), (1659540233, 689689000));
+    /// ```
     #[inline]
-    fn set_version(&mut self, ver: Version) {
-        // `Version` enum matches version layout
-        self.0[6] = (self.0[6] & 0xF) | ((ver as u8) << 4);
+    pub const fn from_gregorian_100ns(ticks: u64) -> Self {
+        Self {
+            version: Version::Time,
+            ticks,
+        }
     }
 
-    /// Set the UUID Variant, only touching bits as specified.
+    /// Create a [`Version::Time`] timestamp from seconds and nanoseconds
+    /// since the Unix epoch.
     ///
-    /// The version field has several unspecified bits, which this method
-    /// leaves alone. Legacy UUID's can thus be modified losslessly.
+    /// Precision is truncated to 100 nanoseconds.
     ///
-    /// When creating UUID's, these unspecified bits should always be zero by
-    /// default anyway.
+    /// Times too far in the future for a `u64` of 100-nanosecond
+    /// intervals, about 58,000 years after the Gregorian epoch,
+    /// saturate to [`u64::MAX`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Timestamp;
+    /// let time = Timestamp::from_unix(1659540233, 689689000);
+    /// assert_eq!(time.to_gregorian_100ns(), 138788330336896890);
+    /// ```
     #[inline]
-    fn set_variant(&mut self, ver: Variant) {
-        let byte = self.0[8];
-        self.0[8] = match ver {
-            // 0xx
-            Variant::Ncs => byte & 0x7F,
-            // 10x
-            Variant::Rfc4122 => (byte & 0x3F) | 0x80,
-            // 110
-            Variant::Microsoft => (byte & 0x1F) | 0xC0,
-            // 111
-            Variant::Reserved => byte | 0xE0,
-        }
+    pub const fn from_unix(secs: u64, nanos: u32) -> Self {
+        Self::from_gregorian_100ns(
+            GREGORIAN_OFFSET
+                .saturating_add(secs.saturating_mul(10_000_000))
+                .saturating_add((nanos / 100) as u64),
+        )
     }
 
-    /// Swap the in-memory format between big-endian and mixed-endian.
+    /// The UUID version this timestamp is for.
     #[inline]
-    const fn swap_endian(mut self) -> Self {
-        // TODO: Const slice reverse pls. or const mem::swap.
-        let (a1, a2, a3, a4) = (self.0[0], self.0[1], self.0[2], self.0[3]);
-        self.0[0] = a4;
-        self.0[1] = a3;
-        self.0[2] = a2;
-        self.0[3] = a1;
-
-        let (a1, a2) = (self.0[4], self.0[5]);
-        self.0[4] = a2;
-        self.0[5] = a1;
-
-        let (a1, a2) = (self.0[6], self.0[7]);
-        self.0[6] = a2;
-        self.0[7] = a1;
-
-        self
+    pub const fn version(self) -> Version {
+        self.version
     }
-}
 
-impl Uuid {
-    /// The special Nil UUID, where all bits are set to zero.
+    /// The timestamp as 100-nanosecond intervals since the Gregorian epoch.
+    ///
+    /// This is the 60-bit timestamp used by [`Version::Time`] and
+    /// [`Version::Database`] UUIDs.
     #[inline]
-    pub const fn nil() -> Self {
-        Uuid([0; 16])
+    pub const fn to_gregorian_100ns(self) -> u64 {
+        match self.version {
+            #[cfg(feature = "experimental_uuid")]
+            Version::UnixTime => (self.ticks * 10_000) + GREGORIAN_OFFSET,
+            _ => self.ticks,
+        }
     }
 
-    /// The special Max UUID, where all bits are set to one.
+    /// The timestamp as seconds and nanoseconds since the Unix epoch.
+    ///
+    /// Gregorian timestamps from before the Unix epoch saturate to zero.
     #[inline]
-    #[cfg(feature = "experimental_uuid")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
-    pub const fn max() -> Self {
-        Uuid([1; 16])
+    pub const fn to_unix(self) -> (u64, u32) {
+        match self.version {
+            #[cfg(feature = "experimental_uuid")]
+            Version::UnixTime => (self.ticks / 1000, (self.ticks % 1000) as u32 * 1_000_000),
+            _ => {
+                let ticks = self.ticks.saturating_sub(GREGORIAN_OFFSET);
+                (ticks / 10_000_000, (ticks % 10_000_000) as u32 * 100)
+            }
+        }
     }
 
-    /// Create a UUID from bytes.
+    /// Whole seconds since the Unix epoch.
+    ///
+    /// See [`Timestamp::to_unix`] for details.
     #[inline]
-    pub const fn from_bytes(bytes: Bytes) -> Self {
-        Self(bytes)
+    pub const fn seconds(self) -> u64 {
+        self.to_unix().0
     }
 
-    /// Return the UUID as it's bytes.
+    /// Fractional part of [`Timestamp::seconds`], in nanoseconds.
+    ///
+    /// See [`Timestamp::to_unix`] for details.
     #[inline]
-    pub const fn to_bytes(self) -> Bytes {
-        self.0
+    pub const fn subsec_nanos(self) -> u32 {
+        self.to_unix().1
     }
 
-    /// Create a UUID from mixed-endian bytes.
-    ///
-    /// The resulting UUID will be stored in-memory as big-endian.
-    ///
-    /// This will primarily come up when interacting with Microsoft GUIDs/UUIDs
+    /// The timestamp as a [`SystemTime`](std::time::SystemTime),
+    /// or [`None`] if the platform can't represent it.
     ///
-    /// The following fields are expected to be little-endian instead of
-    /// big-endian:
-    ///
-    /// - `time_low`
-    /// - `time_mid`
-    /// - `time_hi_and_version`
-    ///
-    /// Other fields are left unchanged
-    #[inline]
-    pub const fn from_bytes_me(bytes: Bytes) -> Self {
-        Self(bytes).swap_endian()
-    }
+    /// Unlike [`Timestamp::to_unix`], Gregorian timestamps from before the
+    /// Unix epoch are supported, if the platform supports them.
+    /// On Windows, times before 1601 are [`None`].
+    #[cfg(any(test, feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn to_system_time(self) -> Option<std::time::SystemTime> {
+        use std::time::{Duration, UNIX_EPOCH};
 
-    /// Return the UUID as mixed-endian bytes.
-    ///
-    /// See [`Uuid::from_bytes_me`] for details.
-    #[inline]
-    pub const fn to_bytes_me(self) -> Bytes {
-        self.swap_endian().to_bytes()
+        let ticks = self.to_gregorian_100ns();
+        if ticks >= GREGORIAN_OFFSET {
+            let (secs, nanos) = self.to_unix();
+            UNIX_EPOCH.checked_add(Duration::new(secs, nanos))
+        } else {
+            UNIX_EPOCH.checked_sub(Duration::from_nanos((GREGORIAN_OFFSET - ticks) * 100))
+        }
     }
+}
 
-    /// Returns true if the UUID is nil.
-    #[inline]
-    pub const fn is_nil(self) -> bool {
-        // Done this way for const reasons
-        // nil is nil regardless of byte order
-        u128::from_ne_bytes(self.0) == 0
+/// 100-nanosecond intervals since the Gregorian epoch for `time`
+///
+/// Times before the Gregorian epoch saturate to zero,
+/// and times too far in the future saturate to [`u64::MAX`].
+#[cfg(any(test, feature = "std"))]
+fn gregorian_from_system_time(time: std::time::SystemTime) -> u64 {
+    match time.duration_since(std::time::UNIX_EPOCH) {
+        Ok(d) => u64::try_from(d.as_nanos() / 100)
+            .ok()
+            .and_then(|t| t.checked_add(GREGORIAN_OFFSET))
+            .unwrap_or(u64::MAX),
+        Err(e) => GREGORIAN_OFFSET.saturating_sub((e.duration().as_nanos() / 100) as u64),
     }
+}
 
-    /// The UUID Variant
+/// Milliseconds since the Unix epoch for `time`
+///
+/// Times before the Unix epoch saturate to zero.
+#[cfg(any(test, feature = "std"))]
+fn unix_ms_from_system_time(time: std::time::SystemTime) -> u64 {
+    time.duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Reverse each 8 byte half of `bytes`
+///
+/// See [`BsonFlavor::Java`].
+const fn reverse_halves(bytes: Bytes) -> Bytes {
+    let n = u128::from_be_bytes(bytes);
+    let hi = ((n >> 64) as u64).swap_bytes() as u128;
+    let lo = (n as u64).swap_bytes() as u128;
+    ((hi << 64) | lo).to_be_bytes()
+}
+
+/// The hex encoder used by [`Uuid::to_str`] and related methods.
+///
+/// See [`HEX_ENCODER`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum HexEncoder {
+    /// Converts all nibbles at once using integer arithmetic.
     ///
-    /// # Warning
+    /// This is the default.
+    Swar,
+
+    /// Looks up each byte in a 512 byte table of digit pairs.
     ///
-    /// Many UUIDs out in the wild are incorrectly generated,
-    /// so this value can't be relied upon.
-    #[inline]
-    pub const fn variant(self) -> Variant {
-        // Check the highest 3 bits, skipping the 5 bits of the time/clock sequence
-        let byte = (self.0[8] >> 5) & 0b111;
+    /// This is used with the `hex-table` feature,
+    /// for platforms where tables are faster than shifts.
+    Table,
+}
 
-        // Done this way for exhaustiveness checking.
-        match (
-            // Msb0
-            byte >> 2 & 1 == 1,
-            // Msb1
-            byte >> 1 & 1 == 1,
-            // Msb2
-            byte & 1 == 1,
-        ) {
-            (false, ..) => Variant::Ncs,
-            (true, false, ..) => Variant::Rfc4122,
-            (true, true, false) => Variant::Microsoft,
-            (true, true, true) => Variant::Reserved,
-        }
+/// The active [`HexEncoder`], for benchmarking.
+#[cfg(not(feature = "hex-table"))]
+pub const HEX_ENCODER: HexEncoder = HexEncoder::Swar;
+
+/// The active [`HexEncoder`], for benchmarking.
+#[cfg(feature = "hex-table")]
+pub const HEX_ENCODER: HexEncoder = HexEncoder::Table;
+
+/// Build [`HEX_DECODE`].
+const fn hex_decode_table() -> [u8; 256] {
+    let mut table = [0xFF; 256];
+    let mut i = 0;
+    while i < 16 {
+        table[HEX_LOWER[i] as usize] = i as u8;
+        table[HEX_LOWER[i].to_ascii_uppercase() as usize] = i as u8;
+        i += 1;
     }
+    table
+}
 
-    /// The UUID Version
-    ///
-    /// For "incorrect" or non-RFC UUIDs, this value may be nonsensical.
-    ///
-    /// If the version bits do not match any known version,
-    /// [`Version::Reserved`] is returned instead.
-    /// As new versions are recognized, this may change.
-    ///
-    /// # Warning
-    ///
-    /// Many UUIDs out in the wild are incorrectly generated,
-    /// so this value can't be relied upon.
-    #[inline]
-    pub const fn version(self) -> Version {
-        // Check the highest 4 bits
-        match (
-            self.0[6] >> 7 & 1 == 1,
-            self.0[6] >> 6 & 1 == 1,
-            self.0[6] >> 5 & 1 == 1,
-            self.0[6] >> 4 & 1 == 1,
-        ) {
-            (false, false, false, false) => Version::Nil,
-            (false, false, false, true) => Version::Time,
-            (false, false, true, false) => Version::Dce,
-            (false, false, true, true) => Version::Md5,
-            (false, true, false, false) => Version::Random,
-            (false, true, false, true) => Version::Sha1,
+/// Build a table of the two hex `digits` for every byte.
+#[cfg(feature = "hex-table")]
+const fn hex_pairs(digits: &[u8; 16]) -> [[u8; 2]; 256] {
+    let mut table = [[0; 2]; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = [digits[i >> 4], digits[i & 0xF]];
+        i += 1;
+    }
+    table
+}
 
-            #[cfg(feature = "experimental_uuid")]
-            (false, true, true, false) => Version::Database,
+/// Encode `n` as 16 hex digits, using SWAR on a `u128`.
+///
+/// Each nibble is spread into its own byte, and then converted to ASCII
+/// all at once, with no branches or table lookups.
+#[cfg(not(feature = "hex-table"))]
+#[inline]
+const fn encode_hex(n: u64, upper: bool) -> [u8; 16] {
+    const ONES: u128 = u128::from_ne_bytes([0x01; 16]);
 
-            #[cfg(feature = "experimental_uuid")]
-            (false, true, true, true) => Version::UnixTime,
+    // Spread each byte into 16 bits, then each nibble into 8 bits.
+    let mut x = n as u128;
+    x = (x | (x << 32)) & 0x0000_0000_FFFF_FFFF_0000_0000_FFFF_FFFF;
+    x = (x | (x << 16)) & 0x0000_FFFF_0000_FFFF_0000_FFFF_0000_FFFF;
+    x = (x | (x << 8)) & 0x00FF_00FF_00FF_00FF_00FF_00FF_00FF_00FF;
+    x = (x | (x << 4)) & (ONES * 0x0F);
 
-            #[cfg(feature = "experimental_uuid")]
-            (true, false, false, false) => Version::Vendor,
+    // 1 in each byte which is a letter, `0xA..=0xF`.
+    let letters = ((x + ONES * 6) >> 4) & ONES;
+    let gap = if upper {
+        b'A' - b'9' - 1
+    } else {
+        b'a' - b'9' - 1
+    };
+    (x + ONES * (b'0' as u128) + letters * (gap as u128)).to_be_bytes()
+}
 
-            _ => Version::Reserved,
-        }
+/// Encode `n` as 16 hex digits, using a table of digit pairs.
+#[cfg(feature = "hex-table")]
+#[inline]
+const fn encode_hex(n: u64, upper: bool) -> [u8; 16] {
+    let table = if upper {
+        &HEX_PAIRS_UPPER
+    } else {
+        &HEX_PAIRS_LOWER
+    };
+    let bytes = n.to_be_bytes();
+    let mut out = [0; 16];
+    let mut i = 0;
+    while i < 8 {
+        let [hi, lo] = table[bytes[i] as usize];
+        out[i * 2] = hi;
+        out[i * 2 + 1] = lo;
+        i += 1;
     }
+    out
+}
 
-    /// The 60-bit UUID timestamp
-    ///
-    /// This value will only make sense for [`Version::Time`] or
-    /// [`Version::Database`] UUIDs
-    ///
-    /// The value of this will depend on [`Uuid::version`]
+/// DCE Security domain
+///
+/// See [`Uuid::dce_domain`].
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum DceDomain {
+    /// The local ID is a POSIX UID.
+    Person,
+
+    /// The local ID is a POSIX GID.
+    Group,
+
+    /// The local ID is an organization ID.
+    Org,
+
+    /// An unknown, site defined, domain.
+    Other(u8),
+}
+
+impl DceDomain {
+    /// The domain as it's raw byte.
     #[inline]
-    pub const fn timestamp(self) -> u64 {
-        match self.version() {
-            #[cfg(feature = "experimental_uuid")]
-            Version::Database => u64::from_be_bytes([
-                // Clear version bits
-                self.0[6] & 0xF,
-                self.0[7],
-                self.0[4],
-                self.0[5],
-                self.0[0],
-                self.0[1],
-                self.0[2],
-                self.0[3],
-            ]),
-            // #[cfg(feature = "experimental_uuid")]
-            // Version::UnixTime => todo!(),
-            _ => u64::from_be_bytes([
-                // Clear version bits
-                self.0[6] & 0xF,
-                self.0[7],
-                self.0[4],
-                self.0[5],
-                self.0[0],
-                self.0[1],
-                self.0[2],
-                self.0[3],
-            ]),
-        }
-    }
-
-    /// The 14-bit UUID clock sequence
-    ///
-    /// This value will only make sense for [`Version::Time`] or
-    /// [`Version::Database`] UUIDs
-    ///
-    /// The value of this will depend on [`Uuid::version`]
-    #[inline]
-    pub const fn clock_sequence(self) -> u16 {
-        u16::from_be_bytes([
-            // Clear variant bits
-            // Only need to clear two because this only makes sense for RFC UUIDs
-            self.0[8] & 0x3F,
-            self.0[9],
-        ])
+    pub const fn to_byte(self) -> u8 {
+        match self {
+            DceDomain::Person => 0,
+            DceDomain::Group => 1,
+            DceDomain::Org => 2,
+            DceDomain::Other(b) => b,
+        }
     }
 
-    /// The 48-bit UUID Node ID
+    /// Create a domain from it's raw byte.
     #[inline]
-    pub const fn node(self) -> [u8; 6] {
-        [
-            self.0[10], self.0[11], self.0[12], self.0[13], self.0[14], self.0[15],
-        ]
+    pub const fn from_byte(byte: u8) -> Self {
+        match byte {
+            0 => DceDomain::Person,
+            1 => DceDomain::Group,
+            2 => DceDomain::Org,
+            b => DceDomain::Other(b),
+        }
     }
+}
 
-    /// Write UUID as a lowercase ASCII string into `buf`, and returns it as a
-    /// string.
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// # use nuuid::Uuid;
-    /// const EXAMPLE_UUID: &str = "662aa7c7-7598-4d56-8bcc-a72c30f998a2";
-    /// let uuid = Uuid::parse(EXAMPLE_UUID)?;
-    ///
-    /// let mut buf = [0u8; 36];
-    /// let string = uuid.to_str(&mut buf);
-    /// assert_eq!(string, EXAMPLE_UUID);
-    /// # Ok(()) }
-    /// ```
-    ///
-    /// With an array
-    ///
-    /// ```rust
-    /// # use nuuid::Uuid;
-    /// let uuid = Uuid::new_v4();
-    /// let mut buf = [0u8; 36];
-    /// let string = uuid.to_str(&mut buf);
-    /// ```
-    ///
-    /// With a slice
-    ///
-    /// ```rust
-    /// # use nuuid::Uuid;
-    /// # use std::convert::TryInto;
-    /// let uuid = Uuid::new_v4();
-    /// let mut data = [0u8; 50];
-    /// let string = uuid.to_str((&mut data[..36]).try_into().unwrap());
-    /// ```
-    ///
-    /// With a slice, incorrectly.
-    ///
-    /// The problem here is that the slices length is unconstrained, and could
-    /// be more or less than 36.
-    ///
-    /// ```rust,should_panic
-    /// # use nuuid::Uuid;
-    /// # use std::convert::TryInto;
-    /// let uuid = Uuid::new_v4();
-    /// let mut data = [0u8; 50];
-    /// let string = uuid.to_str((&mut data[..]).try_into().unwrap());
-    /// ```
-    pub fn to_str(self, buf: &mut [u8; 36]) -> &mut str {
-        // Add hyphens
-        buf[8] = b'-';
-        buf[13] = b'-';
-        buf[18] = b'-';
-        buf[23] = b'-';
-
-        let bytes = self.to_bytes();
+/// Byte order of a legacy MongoDB BSON UUID.
+///
+/// Legacy BSON binary subtype 3 UUIDs were stored in a driver specific
+/// byte order. The standard subtype 4 is always big-endian, see
+/// [`Uuid::from_bytes`].
+///
+/// See [`Uuid::from_bson_legacy`].
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum BsonFlavor {
+    /// The legacy Java driver, where each 8 byte half is reversed.
+    Java,
 
-        let time_low = &bytes[..4];
-        let time_mid = &bytes[4..6];
-        let time_hi_and_version = &bytes[6..8];
-        let clock_seq_hi_and_reserved = &bytes[8..9];
-        let clock_seq_low = &bytes[9..10];
-        let node = &bytes[10..];
+    /// The legacy C# driver, in mixed-endian, see [`Uuid::from_bytes_me`].
+    CSharp,
 
-        // Encode each field of the UUID
-        let _ = hex_simd::encode(time_low, Out::from_slice(&mut buf[..8]), Lower);
-        let _ = hex_simd::encode(time_mid, Out::from_slice(&mut buf[9..13]), Lower);
-        let _ = hex_simd::encode(
-            time_hi_and_version,
-            Out::from_slice(&mut buf[14..18]),
-            Lower,
-        );
-        let _ = hex_simd::encode(
-            clock_seq_hi_and_reserved,
-            Out::from_slice(&mut buf[19..21]),
-            Lower,
-        );
-        let _ = hex_simd::encode(clock_seq_low, Out::from_slice(&mut buf[21..23]), Lower);
-        let _ = hex_simd::encode(node, Out::from_slice(&mut buf[24..]), Lower);
+    /// The legacy Python driver, in big-endian, same as subtype 4.
+    Python,
+}
 
-        debug_assert!(buf.is_ascii(), "BUG: Invalid ASCII in nuuid::Uuid::to_str");
-        // This is consistently faster than using the safe checked variant.
-        // Safety: Fully initialized with ASCII hex
-        unsafe { from_utf8_unchecked_mut(buf) }
-    }
+/// Sort order of UUID key bytes.
+///
+/// See [`Uuid::to_key_bytes`].
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub enum KeyOrder {
+    /// The same order as [`Uuid`]s `Ord`.
+    ///
+    /// Version 6 and 7 UUIDs are already ordered by time.
+    #[default]
+    Bytes,
 
-    /// Write a UUID as a lowercase ASCII string into `buf`, and return it as a
-    /// string.
+    /// Order Version 1 UUIDs by time as well.
     ///
-    /// For usage examples see [`Uuid::to_str`].
-    #[inline]
-    pub fn to_urn(self, buf: &mut [u8; 45]) -> &mut str {
-        buf[..UUID_URN_PREFIX].copy_from_slice(UUID_URN.as_bytes());
-        self.to_str((&mut buf[UUID_URN_PREFIX..]).try_into().unwrap());
-        core::str::from_utf8_mut(buf).expect("BUG: Invalid UTF8")
-    }
+    /// The timestamp of Version 1 UUIDs is moved to the front,
+    /// as in Version 6, keeping the version number.
+    /// Other UUIDs are unchanged.
+    Time,
+}
 
-    /// [`Uuid::to_str`], but uppercase.
+/// A 14-bit UUID clock sequence
+///
+/// See [`Uuid::clock_sequence`].
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ClockSeq(u16);
+
+impl ClockSeq {
+    /// The clock sequence, with the variant bits masked out.
     #[inline]
-    pub fn to_str_upper(self, buf: &mut [u8; 36]) -> &mut str {
-        let s = self.to_str(buf);
-        s.make_ascii_uppercase();
-        s
+    pub const fn get(self) -> u16 {
+        self.0
     }
+}
 
-    /// [`Uuid::to_urn`], but the UUID is uppercase.
+impl From<ClockSeq> for u16 {
     #[inline]
-    pub fn to_urn_upper(self, buf: &mut [u8; 45]) -> &mut str {
-        let s = self.to_urn(buf);
-        s[UUID_URN_PREFIX..].make_ascii_uppercase();
-        s
+    fn from(seq: ClockSeq) -> Self {
+        seq.get()
     }
 }
 
-impl Uuid {
-    /// Parse a [`Uuid`] from a string
-    ///
-    /// This method is case insensitive and supports the following formats:
-    ///
-    /// - `urn:uuid:` `urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2`
-    /// - "Braced" `{662aa7c7-7598-4d56-8bcc-a72c30f998a2}`
-    /// - "Hyphenate" `662aa7c7-7598-4d56-8bcc-a72c30f998a2`
-    /// - "Simple" `662aa7c775984d568bcca72c30f998a2`
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// # use nuuid::Uuid;
-    /// Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
-    /// Uuid::parse("662AA7C7-7598-4D56-8BCC-A72C30F998A2").unwrap();
-    ///
-    /// Uuid::parse("urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
-    /// Uuid::parse("urn:uuid:662AA7C7-7598-4D56-8BCC-A72C30F998A2").unwrap();
-    ///
-    /// Uuid::parse("662aa7c775984d568bcca72c30f998a2").unwrap();
-    /// Uuid::parse("662AA7C775984D568BCCA72C30F998A2").unwrap();
-    ///
-    /// Uuid::parse("{662aa7c7-7598-4d56-8bcc-a72c30f998a2}").unwrap();
-    /// Uuid::parse("{662AA7C7-7598-4D56-8BCC-A72C30F998A2}").unwrap();
-    /// ```
-    pub fn parse(s: &str) -> Result<Self, ParseUuidError> {
-        // Error if input is not ASCII
-        if !s.is_ascii() {
-            return Err(ParseUuidError);
-        }
-
-        let s = match s.len() {
-            UUID_URN_LENGTH => &s[UUID_URN_PREFIX..],
-            UUID_BRACED_LENGTH => &s[1..s.len() - 1],
-            UUID_STR_LENGTH => s,
-            UUID_SIMPLE_LENGTH => {
-                return Ok(Uuid::from_bytes(
-                    u128::from_str_radix(s, 16)
-                        .map_err(|_| ParseUuidError)?
-                        .to_be_bytes(),
-                ));
-            }
-            _ => return Err(ParseUuidError),
-        };
-        let s = s.as_bytes();
+/// Decoded information about a [`Uuid`], for inspection and debugging.
+///
+/// See [`Uuid::decode`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct UuidInfo {
+    /// The decoded UUID
+    pub uuid: Uuid,
 
-        let mut raw = [0; UUID_SIMPLE_LENGTH];
-        // "00000000-0000-0000-0000-000000000000"
-        //          9    14   19   24
-        // - 1
-        // "00000000000000000000000000000000"
-        //          9   13  17  21
-        // - 1
+    /// See [`Uuid::variant`]
+    pub variant: Variant,
 
-        // Copy the UUID to `raw`, but without the hyphens, so we can
-        // decode it in-place.
-        // Benchmarking showed this was much faster than decoding directly
-        // to raw in-between the hyphens.
+    /// See [`Uuid::version`]
+    pub version: Version,
 
-        // Node data
-        raw[20..].copy_from_slice(&s[24..]);
+    /// See [`Uuid::timestamp`]
+    ///
+    /// This is [`None`] for versions without a timestamp.
+    pub timestamp: Option<Timestamp>,
 
-        // High bits of the clock, variant, and low bits of the clock
-        raw[16..20].copy_from_slice(&s[19..23]);
+    /// See [`Uuid::node`]
+    pub node: Option<[u8; 6]>,
 
-        // High bits of the timestamp, and version
-        raw[12..16].copy_from_slice(&s[14..18]);
+    /// See [`Uuid::clock_sequence`]
+    pub clock_seq: Option<ClockSeq>,
+}
 
-        // Middle bits of the timestamp
-        raw[8..12].copy_from_slice(&s[9..13]);
+/// Display the [`UuidInfo`] as human readable lines
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::Uuid;
+/// let uuid = Uuid::new_v1(138788330336896890, 8648, *b"world!");
+/// assert_eq!(
+///     uuid.decode().to_string(),
+///     "UUID: 48b3477a-1340-11ed-a1c8-776f726c6421
+/// Variant: Rfc4122
+/// Version: Time(1)
+/// Timestamp: 1659540233.689689000
+/// Clock Sequence: 8648
+/// Node: 77:6f:72:6c:64:21"
+/// );
+/// ```
+impl fmt::Display for UuidInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "UUID: {:x}", self.uuid)?;
+        write!(f, "\nVariant: {}", self.variant)?;
+        write!(f, "\nVersion: {}({})", self.version, self.version as u8)?;
+        if let Some(time) = self.timestamp {
+            let (secs, nanos) = time.to_unix();
+            write!(f, "\nTimestamp: {}.{:09}", secs, nanos)?;
+        }
+        if let Some(seq) = self.clock_seq {
+            write!(f, "\nClock Sequence: {}", seq.get())?;
+        }
+        if let Some(n) = self.node {
+            write!(
+                f,
+                "\nNode: {:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+                n[0], n[1], n[2], n[3], n[4], n[5]
+            )?;
+        }
+        Ok(())
+    }
+}
 
-        // Low bits of the timestamp
-        raw[..8].copy_from_slice(&s[..8]);
+impl UuidInfo {
+    /// Write the decoded fields for alternate `Debug`.
+    fn fmt_debug_fields(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(time) = self.timestamp {
+            let (secs, nanos) = time.to_unix();
+            writeln!(f, "    Timestamp: {}.{:09},", secs, nanos)?;
+        }
+        if let Some(seq) = self.clock_seq {
+            writeln!(f, "    Clock Sequence: {},", seq.get())?;
+        }
+        if let Some(n) = self.node {
+            writeln!(
+                f,
+                "    Node: {:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x},",
+                n[0], n[1], n[2], n[3], n[4], n[5]
+            )?;
+        }
+        if let (Some(domain), Some(id)) = (self.uuid.dce_domain(), self.uuid.dce_local_id()) {
+            writeln!(f, "    Domain: {:?},", domain)?;
+            writeln!(f, "    Local ID: {},", id)?;
+        }
+        Ok(())
+    }
+}
+
+/// A UUID string format.
+///
+/// See [`ParseUuidError::format`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum UuidFormat {
+    /// `662aa7c7-7598-4d56-8bcc-a72c30f998a2`
+    Hyphenated,
+
+    /// `662aa7c775984d568bcca72c30f998a2`
+    Simple,
+
+    /// `{662aa7c7-7598-4d56-8bcc-a72c30f998a2}`
+    Braced,
+
+    /// `urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2`
+    Urn,
+
+    /// `01H455VB4PEX5VSKNK084SN02Q`, see [`Uuid::parse_ulid`].
+    Ulid,
+}
 
-        let x = decode_inplace(&mut raw).map_err(|_| ParseUuidError)?;
-        Ok(Uuid::from_bytes(x.try_into().map_err(|_| ParseUuidError)?))
+/// The kind of [`ParseUuidError`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input was not the length of any supported format.
+    InvalidLength(usize),
+
+    /// The input contained an invalid character.
+    InvalidCharacter(char),
+
+    /// A hyphen, `-`, was expected between groups.
+    ExpectedHyphen,
+
+    /// The input was not in a supported format.
+    InvalidFormat,
+
+    /// The output was too small for all the parsed UUIDs,
+    /// see [`Uuid::parse_slice`].
+    BufferTooSmall,
+}
+
+/// Error parsing UUID
+///
+/// This describes what went wrong, and where, for precise error messages.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{ParseErrorKind, Uuid, UuidFormat};
+/// let err = Uuid::parse("662aa7c7-7598-4d56-8gcc-a72c30f998a2").unwrap_err();
+/// assert_eq!(err.kind(), ParseErrorKind::InvalidCharacter('g'));
+/// assert_eq!(err.position(), Some(20));
+/// assert_eq!(err.format(), Some(UuidFormat::Hyphenated));
+/// assert_eq!(err.to_string(), "Invalid character 'g' at position 20");
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ParseUuidError {
+    kind: ParseErrorKind,
+    position: Option<usize>,
+    format: Option<UuidFormat>,
+}
+
+impl ParseUuidError {
+    /// An error of `kind` at `position`, while parsing `format`.
+    #[inline]
+    const fn new(
+        kind: ParseErrorKind,
+        position: Option<usize>,
+        format: Option<UuidFormat>,
+    ) -> Self {
+        Self {
+            kind,
+            position,
+            format,
+        }
     }
 
-    /// Parse a [`Uuid`] from a string that is in mixed-endian
-    ///
-    /// This method is bad and should never be needed, but there are UUIDs in
-    /// the wild that do this.
-    ///
-    /// These UUIDs are being displayed wrong, but you still need to parse them
-    /// correctly.
-    ///
-    /// See [`Uuid::from_bytes_me`] for details.
-    pub fn parse_me(s: &str) -> Result<Self, ParseUuidError> {
-        Uuid::from_str(s).map(Uuid::swap_endian)
+    /// An [`ParseErrorKind::InvalidFormat`] error, with no other details.
+    #[inline]
+    pub(crate) const fn invalid() -> Self {
+        Self::new(ParseErrorKind::InvalidFormat, None, None)
     }
 
-    /// Create a new Version 4(Random) UUID.
-    ///
-    /// This requires the `getrandom` feature.
-    ///
-    /// If generating a lot of UUID's very quickly, prefer [`Uuid::new_v4_rng`].
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// # use nuuid::Uuid;
-    /// let uuid = Uuid::new_v4();
-    /// ```
-    #[cfg(feature = "getrandom")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
+    /// An [`ParseErrorKind::InvalidLength`] error.
     #[inline]
-    pub fn new_v4() -> Self {
-        let mut uuid = Uuid::nil();
-        OsRng.fill_bytes(&mut uuid.0);
-        uuid.set_variant(Variant::Rfc4122);
-        uuid.set_version(Version::Random);
-        uuid
+    const fn invalid_length(len: usize, format: Option<UuidFormat>) -> Self {
+        Self::new(ParseErrorKind::InvalidLength(len), None, format)
     }
 
-    /// Create a new Version 4(Random) UUID, using the provided [`Rng`]
-    ///
-    /// This method is useful if you need to generate a lot of UUID's very
-    /// quickly, since it won't create and seed a new RNG each time.
-    ///
-    /// Providing a good seed is left to you, however.
-    /// If a bad seed is used, the resulting UUIDs may not be
-    /// sufficiently random or unique.
-    ///
-    /// # Example
+    /// Find the first error in `s`, which is the length of `format`.
     ///
-    /// ```rust
-    /// # use nuuid::{Rng, Uuid};
-    /// # let seed = [0; 32];
-    /// let mut rng = Rng::from_seed(seed);
-    /// for _ in 0..10 {
-    ///     let uuid = Uuid::new_v4_rng(&mut rng);
-    /// }
-    /// ```
+    /// This is the slow path, only used after parsing has failed.
     #[inline]
-    pub fn new_v4_rng(rng: &mut Rng) -> Self {
-        let mut uuid = Uuid::nil();
-        rng.fill_bytes(&mut uuid.0);
-        uuid.set_variant(Variant::Rfc4122);
-        uuid.set_version(Version::Random);
-        uuid
+    const fn find(s: &[u8], format: UuidFormat) -> Self {
+        // `x` is a hex digit, anything else must match case insensitively.
+        let template: &[u8] = match format {
+            UuidFormat::Hyphenated => b"xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx",
+            UuidFormat::Simple => b"xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx",
+            UuidFormat::Braced => b"{xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx}",
+            UuidFormat::Urn => b"urn:uuid:xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx",
+            UuidFormat::Ulid => b"",
+        };
+
+        let mut i = 0;
+        let (mut rest, mut expected) = (s, template);
+        while let ([b, tail @ ..], [t, t_tail @ ..]) = (rest, expected) {
+            let valid = match *t {
+                b'x' => HEX_DECODE[*b as usize] <= 0xF,
+                b'-' if *b != b'-' => {
+                    return Self::new(ParseErrorKind::ExpectedHyphen, Some(i), Some(format));
+                }
+                t => b.to_ascii_lowercase() == t,
+            };
+            if !valid {
+                let c = first_char(rest);
+                return Self::new(ParseErrorKind::InvalidCharacter(c), Some(i), Some(format));
+            }
+            i += 1;
+            (rest, expected) = (tail, t_tail);
+        }
+        Self::new(ParseErrorKind::InvalidFormat, None, Some(format))
     }
 
-    /// Create a new Version 3 UUID with the provided name and namespace.
-    ///
-    /// # Note
-    ///
-    /// Version 3 UUID's use the obsolete MD5 algorithm,
-    /// [`Uuid::new_v5`] should be preferred.
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// # use nuuid::{NAMESPACE_DNS, Uuid};
-    /// let uuid = Uuid::new_v3(NAMESPACE_DNS, b"example.com");
-    /// ```
+    /// Move the position by `offset`, for parsing part of a larger string.
     #[inline]
-    pub fn new_v3(namespace: Uuid, name: &[u8]) -> Self {
-        let mut hasher = Md5::new();
-        hasher.update(namespace.to_bytes());
-        hasher.update(name);
-        let mut uuid = Uuid::from_bytes(hasher.finalize().into());
-        uuid.set_version(Version::Md5);
-        uuid.set_variant(Variant::Rfc4122);
-        uuid
+    fn offset(mut self, offset: usize) -> Self {
+        self.position = self.position.map(|p| p + offset);
+        self
     }
 
-    /// Create a new Version 5 UUID with the provided name and namespace.
-    ///
-    /// # Example
+    /// A static description of the error, for compile time errors.
     ///
-    /// ```rust
-    /// # use nuuid::{NAMESPACE_DNS, Uuid};
-    /// let uuid = Uuid::new_v5(NAMESPACE_DNS, b"example.com");
-    /// ```
+    /// Used by [`uuid!`], since const panics can't format.
+    #[doc(hidden)]
+    pub const fn __const_message(&self) -> &'static str {
+        match self.kind {
+            ParseErrorKind::InvalidLength(_) => {
+                "Invalid UUID literal: Invalid length, expected a hyphenated, simple, braced, or URN UUID"
+            }
+            ParseErrorKind::InvalidCharacter(_) => "Invalid UUID literal: Invalid character",
+            ParseErrorKind::ExpectedHyphen => "Invalid UUID literal: Expected '-'",
+            ParseErrorKind::InvalidFormat => "Invalid UUID literal",
+            ParseErrorKind::BufferTooSmall => "Invalid UUID literal: Output buffer was too small",
+        }
+    }
+
+    /// What went wrong.
     #[inline]
-    pub fn new_v5(namespace: Uuid, name: &[u8]) -> Self {
-        let mut hasher = Sha1::new();
-        hasher.update(namespace.to_bytes());
-        hasher.update(name);
-        let mut uuid = Uuid::from_bytes(hasher.finalize()[..16].try_into().unwrap());
-        uuid.set_version(Version::Sha1);
-        uuid.set_variant(Variant::Rfc4122);
-        uuid
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
     }
 
-    /// Create a new Version 1 UUID using the provided 60-bit timestamp,
-    /// 14-bit counter, and node.
-    ///
-    /// The 4 high bits of `timestamp` are ignored
-    ///
-    /// The 2 high bits of `counter` are ignored
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// # use nuuid::{NAMESPACE_DNS, Uuid};
-    /// # let (TIMESTAMP, RANDOM, RANDOM_OR_MAC) = (0, 0, [0; 6]);
-    /// let uuid = Uuid::new_v1(TIMESTAMP, RANDOM, RANDOM_OR_MAC);
-    /// ```
+    /// The byte offset in the input of the error, if known.
     #[inline]
-    pub fn new_v1(timestamp: u64, counter: u16, node: [u8; 6]) -> Self {
-        let timestamp = timestamp.to_be_bytes();
-        let counter = counter.to_be_bytes();
-        Uuid::from_bytes([
-            // time_low
-            timestamp[4],
-            timestamp[5],
-            timestamp[6],
-            timestamp[7],
-            // time_mid
-            timestamp[2],
-            timestamp[3],
-            // time_hi Version, ignore highest 4 bits, skip `set_version` and set the version
-            (timestamp[0] & 0xF) | (1u8 << 4),
-            timestamp[1],
-            // clock_seq_hi Variant, skip `set_variant` and set the variant
-            (counter[0] & 0x3F) | 0x80,
-            counter[1],
-            // Node
-            node[0],
-            node[1],
-            node[2],
-            node[3],
-            node[4],
-            node[5],
-        ])
+    pub const fn position(&self) -> Option<usize> {
+        self.position
     }
 
-    /// Create a new Version 6 UUID
-    ///
-    /// This is identical to Version 1 UUIDs (see [`Uuid::new_v1`]),
-    /// except that the timestamp fields are re-ordered
+    /// The format the input was being parsed as, if known.
     ///
-    /// # Example
-    ///
-    /// ```rust
-    /// # use nuuid::{NAMESPACE_DNS, Uuid};
-    /// # let (TIMESTAMP, RANDOM, PSEUDO) = (0, 0, [0; 6]);
-    /// let uuid = Uuid::new_v6(TIMESTAMP, RANDOM, PSEUDO);
-    /// ```
+    /// This is detected from the length of the input.
     #[inline]
-    #[cfg(feature = "experimental_uuid")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
-    pub fn new_v6(timestamp: u64, counter: u16, node: [u8; 6]) -> Self {
-        // Truncate the highest 4 bits
-        // https://www.ietf.org/archive/id/draft-peabody-dispatch-new-uuid-format-04.html#section-6.1-2.14
-        let timestamp = (timestamp << 4).to_be_bytes();
-        let counter = counter.to_be_bytes();
+    pub const fn format(&self) -> Option<UuidFormat> {
+        self.format
+    }
+}
 
-        Uuid::from_bytes([
-            // time_high
-            timestamp[0],
-            timestamp[1],
-            timestamp[2],
-            timestamp[3],
-            // time_mid
-            timestamp[4],
-            timestamp[5],
-            // time_low Version, shift 4 bits, skip `set_version` and set the version
-            (timestamp[6] >> 4) | (6u8 << 4),
-            timestamp[7],
-            // clock_seq_hi Variant, skip `set_variant` and set the variant
-            (counter[0] & 0x3F) | 0x80,
-            counter[1],
-            // Node
-            node[0],
-            node[1],
-            node[2],
-            node[3],
-            node[4],
-            node[5],
-        ])
+impl fmt::Display for ParseUuidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::InvalidLength(len) => write!(f, "Invalid UUID length {len}")?,
+            ParseErrorKind::InvalidCharacter(c) => write!(f, "Invalid character {c:?}")?,
+            ParseErrorKind::ExpectedHyphen => write!(f, "Expected '-'")?,
+            ParseErrorKind::InvalidFormat => write!(f, "Invalid UUID string")?,
+            ParseErrorKind::BufferTooSmall => write!(f, "Output buffer was too small")?,
+        }
+        if let Some(pos) = self.position {
+            write!(f, " at position {pos}")?;
+        }
+        Ok(())
     }
+}
 
-    /// Create a new Version 7 UUID
-    ///
-    /// This is similar to Version 1 and 6 UUIDs, but uses the UNIX epoch
-    /// timestamp source.
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// # use nuuid::{NAMESPACE_DNS, Uuid};
-    /// # let (TIMESTAMP, RAND_A, RAND_B) = (0, 0, 0);
-    /// let uuid = Uuid::new_v7(TIMESTAMP, RAND_A, RAND_B);
-    /// ```
-    #[inline]
-    #[cfg(feature = "experimental_uuid")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
-    pub fn new_v7(timestamp: u64, rand_a: u16, rand_b: u64) -> Self {
-        // Truncate the highest 16 bits
-        // https://www.ietf.org/archive/id/draft-peabody-dispatch-new-uuid-format-04.html#section-6.1-2.14
-        let timestamp = (timestamp << 16).to_be_bytes();
-        let rand_a = rand_a.to_be_bytes();
-        let rand_b = rand_b.to_be_bytes();
+/// Decode the UTF-8 character at the start of `s`.
+const fn first_char(s: &[u8]) -> char {
+    let (len, mut c, mut rest) = match s {
+        [b @ 0x00..=0x7F, ..] => return *b as char,
+        [b @ 0xC0..=0xDF, rest @ ..] => (2, (*b & 0x1F) as u32, rest),
+        [b @ 0xE0..=0xEF, rest @ ..] => (3, (*b & 0x0F) as u32, rest),
+        [b, rest @ ..] => (4, (*b & 0x07) as u32, rest),
+        [] => return char::REPLACEMENT_CHARACTER,
+    };
+    let mut i = 1;
+    while let [b, tail @ ..] = rest {
+        if i == len {
+            break;
+        }
+        c = (c << 6) | (*b & 0x3F) as u32;
+        rest = tail;
+        i += 1;
+    }
+    match char::from_u32(c) {
+        Some(c) => c,
+        None => char::REPLACEMENT_CHARACTER,
+    }
+}
 
-        Uuid::from_bytes([
-            // unix_ts_ms
-            timestamp[0],
-            timestamp[1],
-            timestamp[2],
-            timestamp[3],
-            timestamp[4],
-            timestamp[5],
-            // rand_a Version, ignore highest 4 bits, skip `set_version` and set the version
-            (rand_a[0] & 0xF) | (7u8 << 4),
-            rand_a[1],
-            // rand_b, Variant, skip `set_variant` and set the variant
-            (rand_b[0] & 0x3F) | 0x80,
-            rand_b[1],
-            rand_b[2],
-            rand_b[3],
-            rand_b[4],
-            rand_b[5],
-            rand_b[6],
-            rand_b[7],
-        ])
-    }
+impl core::error::Error for ParseUuidError {}
 
-    /// Create a new Version 8 UUID
-    ///
-    /// This will set the version and variant bits as needed,
-    /// and the input will otherwise be unchanged.
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// # use nuuid::Uuid;
-    /// let uuid = Uuid::new_v8(*b"I Am 16 bytes!!!");
-    /// ```
-    #[inline]
-    #[cfg(feature = "experimental_uuid")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
-    pub fn new_v8(bytes: Bytes) -> Self {
-        let mut uuid = Uuid::from_bytes(bytes);
-        uuid.set_variant(Variant::Rfc4122);
-        uuid.set_version(Version::Vendor);
-        uuid
-    }
-}
+/// General UUID error
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum NuuidError {
+    /// Input was not exactly 16 bytes.
+    InvalidLength,
 
-/// See [`Uuid::parse`] for details.
-impl FromStr for Uuid {
-    type Err = ParseUuidError;
+    /// The UUID was nil.
+    Nil,
 
-    /// See [`Uuid::parse`] for details.
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Uuid::parse(s)
+    /// The UUID was not the expected version.
+    WrongVersion {
+        /// The version of the state.
+        expected: Version,
+
+        /// The version of the UUID.
+        found: Version,
+    },
+
+    /// The UUID was not the [`Variant::Rfc4122`] variant.
+    WrongVariant,
+
+    /// The UUID was expected to be nil, and was not.
+    NotNil,
+
+    /// The UUID was expected to be max, and was not.
+    NotMax,
+
+    /// No entropy was available, such as when `getrandom` fails.
+    EntropyUnavailable,
+
+    /// The UUID was not valid for a vendor specific layout.
+    InvalidLayout,
+
+    /// Input was not an 18 byte DUID-UUID, see [`Duid`].
+    InvalidDuid,
+
+    /// Output buffer was too small.
+    BufferTooSmall,
+}
+
+impl NuuidError {
+    /// A static description of the error, for compile time errors.
+    ///
+    /// Used by the version checked literal macros, such as [`uuid_v4!`],
+    /// since const panics can't format.
+    #[doc(hidden)]
+    pub const fn __const_message(&self) -> &'static str {
+        match self {
+            NuuidError::WrongVersion { .. } => "UUID literal was the wrong version",
+            NuuidError::WrongVariant => "UUID literal was not the RFC 4122 variant",
+            _ => "Invalid UUID literal",
+        }
     }
 }
 
-/// Display the [`Uuid`] in uppercase hex.
-///
-/// # Example
-///
-/// ```rust
-/// # use nuuid::Uuid;
-/// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
-/// assert_eq!(format!("{}", uuid), "662AA7C7-7598-4D56-8BCC-A72C30F998A2");
-/// ```
-impl fmt::Display for Uuid {
+impl fmt::Display for NuuidError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:X}", self)
+        match self {
+            NuuidError::InvalidLength => write!(f, "Invalid length, expected 16 bytes"),
+            NuuidError::Nil => write!(f, "UUID was nil"),
+            NuuidError::WrongVersion { expected, found } => {
+                write!(f, "UUID was version {found}, expected version {expected}")
+            }
+            NuuidError::WrongVariant => write!(f, "UUID was not the RFC 4122 variant"),
+            NuuidError::NotNil => write!(f, "UUID was not nil"),
+            NuuidError::NotMax => write!(f, "UUID was not max"),
+            NuuidError::EntropyUnavailable => write!(f, "Entropy was unavailable"),
+            NuuidError::InvalidLayout => write!(f, "UUID was not valid for the layout"),
+            NuuidError::InvalidDuid => write!(f, "Invalid DUID, expected an 18 byte DUID-UUID"),
+            NuuidError::BufferTooSmall => write!(f, "Output buffer was too small"),
+        }
     }
 }
 
-/// Display the [`Uuid`] debug representation
+impl core::error::Error for NuuidError {}
+
+/// Universally Unique Identifier, or UUID.
 ///
-/// The alternate(`#`) flag can be used to get more more detailed debug
-/// information.
+/// This type is `repr(transparent)` and guaranteed to have the same layout
+/// as `[u8; 16]`.
 ///
-/// # Example
+/// The various methods on `Uuid` assume each field
+/// is laid out Most Significant Byte First/MSB/Big-Endian/Network Endian.
 ///
-/// ```rust
-/// # use nuuid::Uuid;
-/// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
-/// assert_eq!(format!("{:?}", uuid), "Uuid(662AA7C7-7598-4D56-8BCC-A72C30F998A2)");
-/// assert_eq!(format!("{:#?}", uuid), r#"Uuid(662AA7C7-7598-4D56-8BCC-A72C30F998A2) {
-///     Version: Random(4),
-///     Variant: Rfc4122(1),
-/// }"#);
-/// ```
-impl fmt::Debug for Uuid {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if f.alternate() {
-            write!(
-                f,
-                r#"Uuid({:X}) {{
-    Version: {}({}),
-    Variant: {}({}),
-}}"#,
-                self,
-                self.version(),
-                self.version() as u8,
-                self.variant(),
-                self.variant() as u8
-            )
+/// When the `serde` feature is enabled, this type serializes as a string
+/// for human-readable formats, and as bytes otherwise.
+/// See the [`serde`](crate::serde) module for details.
+///
+/// When the `zerocopy` feature is enabled, this type implements
+/// `FromBytes`, `IntoBytes`, `Unaligned`, `KnownLayout`, and `Immutable`.
+///
+/// The `S` parameter is a [`State`] recording what is statically known
+/// about the UUID, see the [`state`] module for details.
+/// It defaults to [`Unknown`], and has no runtime cost.
+#[derive(Copy, Clone, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(FromBytes, IntoBytes, Unaligned, KnownLayout, Immutable)
+)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Uuid),
+    diesel(sql_type = diesel::sql_types::Binary)
+)]
+#[repr(transparent)]
+pub struct Uuid<S = Unknown>(Bytes, PhantomData<S>);
+
+impl<S: State> Uuid<S> {
+    /// Change the UUID state, without checking.
+    #[inline]
+    const fn cast<T: State>(self) -> Uuid<T> {
+        Uuid(self.0, PhantomData)
+    }
+
+    /// Change the UUID state, if it is valid for `T`.
+    #[inline]
+    fn try_cast<T: State>(self) -> Option<Uuid<T>> {
+        if T::is_valid(self.cast()) {
+            Some(self.cast())
         } else {
-            write!(f, "Uuid({:X})", self)
+            None
         }
     }
-}
 
-/// Display the [`Uuid`] in lowercase
-///
-/// The alternate(`#`) flag can be used to get a URN.
-///
-/// # Example
-///
-/// ```rust
-/// # use nuuid::Uuid;
-/// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
-/// assert_eq!(format!("{:x}", uuid), "662aa7c7-7598-4d56-8bcc-a72c30f998a2");
-/// assert_eq!(format!("{:#x}", uuid), "urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2");
-/// ```
-impl fmt::LowerHex for Uuid {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if f.alternate() {
-            write!(f, "{}", UUID_URN)?;
+    /// Change the UUID state, or return why it is not valid for `T`.
+    #[inline]
+    fn check_cast<T: State>(self) -> Result<Uuid<T>, NuuidError> {
+        T::check(self.cast()).map(|()| self.cast())
+    }
+
+    /// Set the UUID Version.
+    #[inline]
+    const fn set_version(&mut self, ver: Version) {
+        // `Version` enum matches version layout
+        self.0[6] = (self.0[6] & 0xF) | ((ver as u8) << 4);
+    }
+
+    /// Set the UUID Variant, only touching bits as specified.
+    ///
+    /// The version field has several unspecified bits, which this method
+    /// leaves alone. Legacy UUID's can thus be modified losslessly.
+    ///
+    /// When creating UUID's, these unspecified bits should always be zero by
+    /// default anyway.
+    #[inline]
+    const fn set_variant(&mut self, ver: Variant) {
+        let byte = self.0[8];
+        self.0[8] = match ver {
+            // 0xx
+            Variant::Ncs => byte & 0x7F,
+            // 10x
+            Variant::Rfc4122 => (byte & 0x3F) | 0x80,
+            // 110
+            Variant::Microsoft => (byte & 0x1F) | 0xC0,
+            // 111
+            Variant::Reserved => byte | 0xE0,
         }
-        let mut buf = [0; 36];
-        let s = self.to_str(&mut buf);
-        write!(f, "{}", s)
+    }
+
+    /// Swap the in-memory format between big-endian and mixed-endian.
+    #[inline]
+    const fn swap_endian(mut self) -> Self {
+        // TODO: Const slice reverse pls. or const mem::swap.
+        let (a1, a2, a3, a4) = (self.0[0], self.0[1], self.0[2], self.0[3]);
+        self.0[0] = a4;
+        self.0[1] = a3;
+        self.0[2] = a2;
+        self.0[3] = a1;
+
+        let (a1, a2) = (self.0[4], self.0[5]);
+        self.0[4] = a2;
+        self.0[5] = a1;
+
+        let (a1, a2) = (self.0[6], self.0[7]);
+        self.0[6] = a2;
+        self.0[7] = a1;
+
+        self
     }
 }
 
-/// Display the [`Uuid`] in uppercase
+/// UUIDs compare by their bytes, regardless of state.
 ///
-/// The alternate(`#`) flag can be used to get a URN.
+/// This is done as a single `u128`, which is faster than comparing bytes.
 ///
 /// # Example
 ///
 /// ```rust
-/// # use nuuid::Uuid;
-/// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
-/// assert_eq!(format!("{:X}", uuid), "662AA7C7-7598-4D56-8BCC-A72C30F998A2");
-/// assert_eq!(format!("{:#X}", uuid), "urn:uuid:662AA7C7-7598-4D56-8BCC-A72C30F998A2");
+/// # use nuuid::{Rng, Uuid};
+/// # let mut rng = Rng::from_seed([0; 32]);
+/// let uuid = Uuid::new_v4_rng(&mut rng);
+/// let parsed = Uuid::parse(&uuid.to_string()).unwrap();
+/// assert_eq!(uuid, parsed);
 /// ```
-impl fmt::UpperHex for Uuid {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if f.alternate() {
-            write!(f, "{}", UUID_URN)?;
-        }
-        let mut buf = [0; 36];
-        write!(f, "{}", self.to_str_upper(&mut buf))
+impl<S: State, T: State> PartialEq<Uuid<T>> for Uuid<S> {
+    #[inline]
+    fn eq(&self, other: &Uuid<T>) -> bool {
+        // Equality doesn't care about byte order
+        u128::from_ne_bytes(self.0) == u128::from_ne_bytes(other.0)
+    }
+}
+
+impl<S: State> Eq for Uuid<S> {}
+
+impl<S: State, T: State> PartialOrd<Uuid<T>> for Uuid<S> {
+    #[inline]
+    fn partial_cmp(&self, other: &Uuid<T>) -> Option<Ordering> {
+        Some(u128::from_be_bytes(self.0).cmp(&u128::from_be_bytes(other.0)))
+    }
+}
+
+impl<S: State> Ord for Uuid<S> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Big-endian integers order the same as their bytes
+        u128::from_be_bytes(self.0).cmp(&u128::from_be_bytes(other.0))
+    }
+}
+
+/// The Nil UUID, see [`Uuid::nil`].
+impl Default for Uuid {
+    #[inline]
+    fn default() -> Self {
+        Uuid::nil().erase()
+    }
+}
+
+/// Const constructors checking the version and variant,
+/// for [`Uuid`]s impl.
+macro_rules! versioned_from_bytes {
+    ($(#[doc = $ver:literal] $(#[$meta:meta])* $name:ident, $state:ident;)*) => {
+        $(
+            #[doc = concat!("Create a ", $ver, " UUID from bytes, checking the version and variant.")]
+            ///
+            /// This is a `const fn`, so static tables of UUIDs can be
+            /// checked at compile time.
+            ///
+            /// # Errors
+            ///
+            /// - [`NuuidError::WrongVariant`] if the UUID is not the RFC 4122 variant.
+            /// - [`NuuidError::WrongVersion`] if the UUID is the wrong version.
+            $(#[$meta])*
+            #[inline]
+            pub const fn $name(bytes: Bytes) -> Result<Uuid<$state>, NuuidError> {
+                Uuid::from_bytes(bytes).__const_versioned()
+            }
+        )*
+    };
+}
+
+impl Uuid {
+    /// The special Nil UUID, where all bits are set to zero.
+    ///
+    /// See [`Uuid::nil`] for the [`RfcNil`] state.
+    pub const NIL: Uuid = Uuid::from_bytes([0; 16]);
+
+    /// The special Max UUID, where all bits are set to one.
+    pub const MAX: Uuid = Uuid::from_bytes([0xFF; 16]);
+
+    /// Length of a hyphenated UUID string,
+    /// `662aa7c7-7598-4d56-8bcc-a72c30f998a2`, see [`Uuid::to_str`].
+    pub const HYPHENATED_LEN: usize = UUID_STR_LENGTH;
+
+    /// Length of a simple UUID string, `662aa7c775984d568bcca72c30f998a2`.
+    pub const SIMPLE_LEN: usize = UUID_SIMPLE_LENGTH;
+
+    /// Length of a URN UUID string,
+    /// `urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2`, see [`Uuid::to_urn`].
+    pub const URN_LEN: usize = UUID_URN_LENGTH;
+
+    /// Length of a braced UUID string,
+    /// `{662aa7c7-7598-4d56-8bcc-a72c30f998a2}`.
+    pub const BRACED_LEN: usize = UUID_BRACED_LENGTH;
+
+    /// Length of a Rust literal,
+    /// `uuid!("662aa7c7-7598-4d56-8bcc-a72c30f998a2")`,
+    /// see [`Uuid::as_rust_literal`].
+    pub const RUST_LITERAL_LEN: usize = UUID_RUST_LENGTH;
+
+    /// Length of a C array initializer, `{0x66,0x2a,...}`,
+    /// see [`Uuid::as_c_array`].
+    pub const C_ARRAY_LEN: usize = UUID_C_LENGTH;
+
+    /// The special Nil UUID, where all bits are set to zero.
+    ///
+    /// This is the only constructor of the [`RfcNil`] state.
+    /// Use [`Uuid::erase`] or [`Uuid::default`] for an [`Unknown`] nil UUID.
+    #[inline]
+    pub const fn nil() -> Uuid<RfcNil> {
+        Uuid([0; 16], PhantomData)
+    }
+
+    /// The special Max UUID, where all bits are set to one.
+    #[inline]
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    pub const fn max() -> Uuid<RfcMax> {
+        Uuid([0xFF; 16], PhantomData)
+    }
+
+    /// Create a UUID from bytes.
+    #[inline]
+    pub const fn from_bytes(bytes: Bytes) -> Self {
+        Self(bytes, PhantomData)
+    }
+
+    versioned_from_bytes!(
+        /// Version 1
+        from_bytes_v1, RfcV1;
+        /// Version 2
+        from_bytes_v2, RfcV2;
+        /// Version 3
+        from_bytes_v3, RfcV3;
+        /// Version 4
+        from_bytes_v4, RfcV4;
+        /// Version 5
+        from_bytes_v5, RfcV5;
+        /// Version 6
+        #[cfg(feature = "experimental_uuid")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+        from_bytes_v6, RfcV6;
+        /// Version 7
+        #[cfg(feature = "experimental_uuid")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+        from_bytes_v7, RfcV7;
+        /// Version 8, with the default [`AnyLayout`](state::AnyLayout)
+        #[cfg(feature = "experimental_uuid")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+        from_bytes_v8, RfcV8;
+    );
+
+    /// Create a UUID from a slice of bytes.
+    ///
+    /// # Errors
+    ///
+    /// - [`NuuidError::InvalidLength`] if `bytes` is not exactly 16 bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NAMESPACE_DNS, NuuidError, Uuid};
+    /// let packet = [NAMESPACE_DNS.get().to_bytes(); 2].concat();
+    /// assert_eq!(Uuid::from_slice(&packet[16..]), Ok(NAMESPACE_DNS.get()));
+    /// assert_eq!(Uuid::from_slice(&packet[15..]), Err(NuuidError::InvalidLength));
+    /// ```
+    #[inline]
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, NuuidError> {
+        bytes
+            .try_into()
+            .map(Uuid::from_bytes)
+            .map_err(|_| NuuidError::InvalidLength)
+    }
+
+    /// View bytes as a UUID, without copying.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NAMESPACE_DNS, Uuid};
+    /// let bytes = NAMESPACE_DNS.get().to_bytes();
+    /// assert_eq!(Uuid::from_ref(&bytes), &NAMESPACE_DNS.get());
+    /// ```
+    #[inline]
+    pub const fn from_ref(bytes: &Bytes) -> &Self {
+        // Safety: `Uuid` is `repr(transparent)` over `Bytes`
+        unsafe { &*(bytes as *const Bytes as *const Self) }
+    }
+
+    /// View a slice of bytes as a slice of UUIDs, without copying.
+    ///
+    /// See [`Uuid::from_ref`] for details.
+    #[inline]
+    pub const fn from_ref_slice(bytes: &[Bytes]) -> &[Self] {
+        // Safety: `Uuid` is `repr(transparent)` over `Bytes`
+        unsafe { core::slice::from_raw_parts(bytes.as_ptr() as *const Self, bytes.len()) }
+    }
+
+    /// View a packed array of UUIDs as a slice of UUIDs, without copying.
+    ///
+    /// This is useful for parsing on-disk tables of UUIDs, such as GPT
+    /// partition entry arrays.
+    ///
+    /// # Errors
+    ///
+    /// - [`NuuidError::InvalidLength`] if `bytes` is not a multiple of 16
+    ///   bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NAMESPACE_DNS, NAMESPACE_URL, Uuid};
+    /// let table = [NAMESPACE_DNS.get().to_bytes(), NAMESPACE_URL.get().to_bytes()].concat();
+    /// let uuids = Uuid::from_packed(&table).unwrap();
+    /// assert_eq!(uuids, [NAMESPACE_DNS.get(), NAMESPACE_URL.get()]);
+    /// assert_eq!(Uuid::as_packed(uuids), &table[..]);
+    ///
+    /// assert!(Uuid::from_packed(&table[1..]).is_err());
+    /// ```
+    #[inline]
+    pub const fn from_packed(bytes: &[u8]) -> Result<&[Self], NuuidError> {
+        if bytes.len() % 16 != 0 {
+            return Err(NuuidError::InvalidLength);
+        }
+        // Safety: `Uuid` is `repr(transparent)` over `Bytes`, which has an
+        // alignment of 1, and the length was checked above.
+        Ok(unsafe { core::slice::from_raw_parts(bytes.as_ptr() as *const Self, bytes.len() / 16) })
+    }
+
+    /// Create a UUID from mixed-endian bytes.
+    ///
+    /// The resulting UUID will be stored in-memory as big-endian.
+    ///
+    /// This will primarily come up when interacting with Microsoft GUIDs/UUIDs
+    ///
+    /// The following fields are expected to be little-endian instead of
+    /// big-endian:
+    ///
+    /// - `time_low`
+    /// - `time_mid`
+    /// - `time_hi_and_version`
+    ///
+    /// Other fields are left unchanged
+    #[inline]
+    pub const fn from_bytes_me(bytes: Bytes) -> Self {
+        Self(bytes, PhantomData).swap_endian()
+    }
+
+    /// Create a UUID from the bytes of a legacy BSON binary subtype 3 UUID,
+    /// in the byte order used by `flavor`.
+    ///
+    /// Standard BSON binary subtype 4 UUIDs should use [`Uuid::from_bytes`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{BsonFlavor, Uuid};
+    /// let bytes = [
+    ///     0x56, 0x4D, 0x98, 0x75, 0xC7, 0xA7, 0x2A, 0x66,
+    ///     0xA2, 0x98, 0xF9, 0x30, 0x2C, 0xA7, 0xCC, 0x8B,
+    /// ];
+    /// let uuid = Uuid::from_bson_legacy(bytes, BsonFlavor::Java);
+    /// assert_eq!(uuid, Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap());
+    /// assert_eq!(uuid.to_bson_legacy(BsonFlavor::Java), bytes);
+    /// ```
+    #[inline]
+    pub const fn from_bson_legacy(bytes: Bytes, flavor: BsonFlavor) -> Self {
+        match flavor {
+            BsonFlavor::Java => Self(reverse_halves(bytes), PhantomData),
+            BsonFlavor::CSharp => Self::from_bytes_me(bytes),
+            BsonFlavor::Python => Self::from_bytes(bytes),
+        }
+    }
+
+    /// Create a UUID from the bytes of an SMBIOS System Information
+    /// structure, for SMBIOS version `major.minor`.
+    ///
+    /// Since SMBIOS 2.6, the first three fields are little-endian,
+    /// see [`Uuid::from_bytes_me`]. Earlier versions are big-endian.
+    ///
+    /// Returns [`None`] if all bytes are zero or all are one, which mean the
+    /// UUID is not set or not present.
+    ///
+    /// See the `dmi` module for reading the system UUID on Linux.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let bytes = [
+    ///     0xC7, 0xA7, 0x2A, 0x66, 0x98, 0x75, 0x56, 0x4D,
+    ///     0x8B, 0xCC, 0xA7, 0x2C, 0x30, 0xF9, 0x98, 0xA2,
+    /// ];
+    /// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// assert_eq!(Uuid::from_smbios(bytes, 3, 4), Some(uuid));
+    /// assert_eq!(Uuid::from_smbios(uuid.to_bytes(), 2, 5), Some(uuid));
+    /// assert_eq!(Uuid::from_smbios([0xFF; 16], 3, 4), None);
+    /// ```
+    #[inline]
+    pub const fn from_smbios(bytes: Bytes, major: u8, minor: u8) -> Option<Self> {
+        match u128::from_ne_bytes(bytes) {
+            0 | u128::MAX => None,
+            _ if major > 2 || (major == 2 && minor >= 6) => Some(Self::from_bytes_me(bytes)),
+            _ => Some(Self::from_bytes(bytes)),
+        }
+    }
+
+    /// Create a UUID from an Active Directory `objectGUID` attribute.
+    ///
+    /// `objectGUID` is 16 mixed-endian bytes, see [`Uuid::from_bytes_me`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let object_guid = [
+    ///     0xC7, 0xA7, 0x2A, 0x66, 0x98, 0x75, 0x56, 0x4D,
+    ///     0x8B, 0xCC, 0xA7, 0x2C, 0x30, 0xF9, 0x98, 0xA2,
+    /// ];
+    /// let uuid = Uuid::from_ad_object_guid(&object_guid);
+    /// assert_eq!(uuid, Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap());
+    /// assert_eq!(uuid.to_ad_object_guid(), object_guid);
+    /// ```
+    #[inline]
+    pub const fn from_ad_object_guid(bytes: &Bytes) -> Self {
+        Self::from_bytes_me(*bytes)
+    }
+
+    /// Create a UUID from key bytes in `order`.
+    ///
+    /// See [`Uuid::to_key_bytes`] for details.
+    #[inline]
+    pub const fn from_key_bytes(key: Bytes, order: KeyOrder) -> Self {
+        let uuid = Self::from_bytes(key);
+        if !uuid.is_time_key(order) {
+            return uuid;
+        }
+        let k = key;
+        let ticks = u64::from_be_bytes([0, 0, k[0], k[1], k[2], k[3], k[4], k[5]]) << 12
+            | ((k[6] & 0xF) as u64) << 8
+            | k[7] as u64;
+        let low = (ticks as u32).to_be_bytes();
+        let mid = ((ticks >> 32) as u16).to_be_bytes();
+        let hi = ((ticks >> 48) as u16 | 0x1000).to_be_bytes();
+        Self::from_bytes([
+            low[0], low[1], low[2], low[3], mid[0], mid[1], hi[0], hi[1], //
+            k[8], k[9], k[10], k[11], k[12], k[13], k[14], k[15],
+        ])
+    }
+
+    /// Create a UUID from the bytes of MySQL's `UUID_TO_BIN(uuid, swap)`.
+    ///
+    /// If `swap` is true, the time fields are swapped as MySQL does,
+    /// with `time_hi_and_version` and `time_mid` before `time_low`,
+    /// so Version 1 UUIDs are stored in time order.
+    ///
+    /// This is the same as MySQL's `BIN_TO_UUID(bytes, swap)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// // SELECT HEX(UUID_TO_BIN('6ccd780c-baba-1026-9564-5b8c656024db', 1));
+    /// let bytes = [
+    ///     0x10, 0x26, 0xBA, 0xBA, 0x6C, 0xCD, 0x78, 0x0C,
+    ///     0x95, 0x64, 0x5B, 0x8C, 0x65, 0x60, 0x24, 0xDB,
+    /// ];
+    /// let uuid = Uuid::from_mysql_bin(bytes, true);
+    /// assert_eq!(uuid, Uuid::parse("6ccd780c-baba-1026-9564-5b8c656024db").unwrap());
+    /// assert_eq!(uuid.to_mysql_bin(true), bytes);
+    /// ```
+    #[inline]
+    pub const fn from_mysql_bin(bytes: Bytes, swap: bool) -> Self {
+        if !swap {
+            return Self::from_bytes(bytes);
+        }
+        let b = bytes;
+        Self::from_bytes([
+            b[4], b[5], b[6], b[7], b[2], b[3], b[0], b[1], //
+            b[8], b[9], b[10], b[11], b[12], b[13], b[14], b[15],
+        ])
+    }
+
+    /// Create a UUID from a `u128`.
+    ///
+    /// The most significant byte of `n` is the first byte of the UUID.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NAMESPACE_DNS, Uuid};
+    /// let uuid = Uuid::from_u128(0x6ba7b810_9dad_11d1_80b4_00c04fd430c8);
+    /// assert_eq!(uuid, NAMESPACE_DNS.get());
+    /// assert_eq!(uuid.to_u128(), 0x6ba7b810_9dad_11d1_80b4_00c04fd430c8);
+    /// ```
+    #[inline]
+    pub const fn from_u128(n: u128) -> Self {
+        Self(n.to_be_bytes(), PhantomData)
+    }
+
+    /// Create a UUID from an `i128`.
+    ///
+    /// This is the two's complement reinterpretation of [`Uuid::from_u128`],
+    /// as used by databases and formats which store UUIDs as signed 128-bit
+    /// integers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::from_i128(-1);
+    /// assert_eq!(uuid.to_u128(), u128::MAX);
+    /// assert_eq!(uuid.to_i128(), -1);
+    /// ```
+    #[inline]
+    pub const fn from_i128(n: i128) -> Self {
+        Self(n.to_be_bytes(), PhantomData)
+    }
+
+    /// Create a UUID from its high and low 64 bits.
+    ///
+    /// This is useful for formats without 128-bit integers,
+    /// such as Protocol Buffers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NAMESPACE_DNS, Uuid};
+    /// let uuid = Uuid::from_u64_pair(0x6ba7b810_9dad_11d1, 0x80b4_00c04fd430c8);
+    /// assert_eq!(uuid, NAMESPACE_DNS.get());
+    /// assert_eq!(uuid.to_u64_pair(), (0x6ba7b810_9dad_11d1, 0x80b4_00c04fd430c8));
+    /// ```
+    #[inline]
+    pub const fn from_u64_pair(high: u64, low: u64) -> Self {
+        Self::from_u128(((high as u128) << 64) | low as u128)
+    }
+
+    /// Create a UUID from a little-endian `u128`.
+    ///
+    /// The least significant byte of `n` is the first byte of the UUID,
+    /// the entire UUID is reversed.
+    ///
+    /// This differs from mixed-endian, see [`Uuid::from_bytes_me`] and
+    /// [`Uuid::from_fields_me`] for those.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NAMESPACE_DNS, Uuid};
+    /// let uuid = Uuid::from_u128_le(0xc830d44f_c000_b480_d111_ad9d10b8a76b);
+    /// assert_eq!(uuid, NAMESPACE_DNS.get());
+    /// ```
+    #[inline]
+    pub const fn from_u128_le(n: u128) -> Self {
+        Self(n.to_le_bytes(), PhantomData)
+    }
+
+    /// Expand a 16 or 32-bit Bluetooth UUID using the
+    /// [`BLUETOOTH_BASE_UUID`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// // Heart Rate Service
+    /// let uuid = Uuid::from_bt_short(0x180D);
+    /// assert_eq!(uuid.to_string(), "0000180D-0000-1000-8000-00805F9B34FB");
+    /// assert_eq!(uuid.to_bt_short(), Some(0x180D));
+    /// ```
+    #[inline]
+    pub const fn from_bt_short(short: u32) -> Self {
+        let mut uuid = BLUETOOTH_BASE_UUID;
+        let short = short.to_be_bytes();
+        uuid.0[0] = short[0];
+        uuid.0[1] = short[1];
+        uuid.0[2] = short[2];
+        uuid.0[3] = short[3];
+        uuid
+    }
+
+    /// Create a UUID from the classic RFC fields.
+    ///
+    /// - `d1` is `time_low`
+    /// - `d2` is `time_mid`
+    /// - `d3` is `time_hi_and_version`
+    /// - `d4` is the clock sequence and node
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NAMESPACE_DNS, Uuid};
+    /// let uuid = Uuid::from_fields(
+    ///     0x6ba7b810,
+    ///     0x9dad,
+    ///     0x11d1,
+    ///     [0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8],
+    /// );
+    /// assert_eq!(uuid, NAMESPACE_DNS.get());
+    /// ```
+    #[inline]
+    pub const fn from_fields(d1: u32, d2: u16, d3: u16, d4: [u8; 8]) -> Self {
+        let (d1, d2, d3) = (d1.to_be_bytes(), d2.to_be_bytes(), d3.to_be_bytes());
+        Self(
+            [
+                d1[0], d1[1], d1[2], d1[3], //
+                d2[0], d2[1], //
+                d3[0], d3[1], //
+                d4[0], d4[1], d4[2], d4[3], d4[4], d4[5], d4[6], d4[7],
+            ],
+            PhantomData,
+        )
+    }
+
+    /// Create a UUID from mixed-endian fields.
+    ///
+    /// This is for fields which were read with the wrong byte order,
+    /// see [`Uuid::from_bytes_me`] for details.
+    ///
+    /// `d1`, `d2`, and `d3` are byte swapped, `d4` is left unchanged.
+    #[inline]
+    pub const fn from_fields_me(d1: u32, d2: u16, d3: u16, d4: [u8; 8]) -> Self {
+        Self::from_fields(d1.swap_bytes(), d2.swap_bytes(), d3.swap_bytes(), d4)
+    }
+}
+
+impl<S: State> Uuid<S> {
+    /// Change the UUID state to `T`, if it is valid for `T`.
+    ///
+    /// Returns the UUID unchanged if it is not.
+    ///
+    /// See [`state`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{state::{RfcV4, RfcV5}, Uuid};
+    /// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// assert!(uuid.into_state::<RfcV5>().is_err());
+    /// let uuid: Uuid<RfcV4> = uuid.into_state().unwrap();
+    /// ```
+    #[inline]
+    pub fn into_state<T: State>(self) -> Result<Uuid<T>, Self> {
+        self.try_cast().ok_or(self)
+    }
+
+    /// Erase the UUID state, returning an [`Unknown`] UUID.
+    ///
+    /// This is useful for storing UUIDs of different states together.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Rng, Uuid, NAMESPACE_DNS};
+    /// # let mut rng = Rng::from_seed([0; 32]);
+    /// let uuids: Vec<Uuid> = vec![
+    ///     Uuid::new_v4_rng(&mut rng).erase(),
+    ///     Uuid::new_v5(NAMESPACE_DNS, b"example.com").into(),
+    /// ];
+    /// ```
+    #[inline]
+    pub const fn erase(self) -> Uuid {
+        self.cast()
+    }
+
+    /// View the UUID as state `T`, if it is valid for `T`.
+    ///
+    /// See [`Uuid::into_state`] for details.
+    #[inline]
+    pub fn as_state<T: State>(&self) -> Option<&Uuid<T>> {
+        if T::is_valid(self.cast()) {
+            // Safety: `Uuid` is `repr(transparent)` over `Bytes`,
+            // the state is a zero-sized marker.
+            Some(unsafe { &*(self as *const Self as *const Uuid<T>) })
+        } else {
+            None
+        }
+    }
+
+    /// Return the UUID as it's bytes.
+    #[inline]
+    pub const fn to_bytes(self) -> Bytes {
+        self.0
+    }
+
+    /// View a slice of UUIDs as a slice of bytes, without copying.
+    ///
+    /// This is the inverse of [`Uuid::from_ref_slice`].
+    #[inline]
+    pub const fn as_bytes_slice(uuids: &[Self]) -> &[Bytes] {
+        // Safety: `Uuid` is `repr(transparent)` over `Bytes`
+        unsafe { core::slice::from_raw_parts(uuids.as_ptr() as *const Bytes, uuids.len()) }
+    }
+
+    /// View a slice of UUIDs as packed bytes, without copying.
+    ///
+    /// This is the inverse of [`Uuid::from_packed`].
+    #[inline]
+    pub const fn as_packed(uuids: &[Self]) -> &[u8] {
+        // Safety: `Uuid` is `repr(transparent)` over `Bytes`
+        unsafe { core::slice::from_raw_parts(uuids.as_ptr() as *const u8, uuids.len() * 16) }
+    }
+
+    /// Return the UUID as mixed-endian bytes.
+    ///
+    /// See [`Uuid::from_bytes_me`] for details.
+    #[inline]
+    pub const fn to_bytes_me(self) -> Bytes {
+        self.swap_endian().to_bytes()
+    }
+
+    /// Return the UUID as the bytes of a legacy BSON binary subtype 3 UUID,
+    /// in the byte order used by `flavor`.
+    ///
+    /// See [`Uuid::from_bson_legacy`] for details.
+    #[inline]
+    pub const fn to_bson_legacy(self, flavor: BsonFlavor) -> Bytes {
+        match flavor {
+            BsonFlavor::Java => reverse_halves(self.0),
+            BsonFlavor::CSharp => self.to_bytes_me(),
+            BsonFlavor::Python => self.to_bytes(),
+        }
+    }
+
+    /// Return the UUID as an Active Directory `objectGUID` attribute.
+    ///
+    /// See [`Uuid::from_ad_object_guid`] for details.
+    #[inline]
+    pub const fn to_ad_object_guid(self) -> Bytes {
+        self.to_bytes_me()
+    }
+
+    /// Return the UUID as key bytes, which sort in `order`.
+    ///
+    /// This is useful for ordered key-value stores, such as RocksDB,
+    /// which sort keys bytewise.
+    ///
+    /// With [`KeyOrder::Bytes`], this is the same as [`Uuid::to_bytes`].
+    /// With [`KeyOrder::Time`], Version 1 UUIDs are rearranged to sort by
+    /// time. In both, Version 6 and 7 UUIDs sort by time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{KeyOrder, Uuid};
+    /// let early = Uuid::new_v1(0xFFFF_FFFF, 0, [0; 6]);
+    /// let late = Uuid::new_v1(0x1_0000_0000, 0, [0; 6]);
+    /// assert!(early.to_key_bytes(KeyOrder::Bytes) > late.to_key_bytes(KeyOrder::Bytes));
+    /// assert!(early.to_key_bytes(KeyOrder::Time) < late.to_key_bytes(KeyOrder::Time));
+    ///
+    /// let key = early.to_key_bytes(KeyOrder::Time);
+    /// assert_eq!(Uuid::from_key_bytes(key, KeyOrder::Time), early);
+    /// ```
+    #[inline]
+    pub const fn to_key_bytes(self, order: KeyOrder) -> Bytes {
+        if !self.is_time_key(order) {
+            return self.to_bytes();
+        }
+        let t = self.timestamp_unchecked().ticks;
+        let high = (t >> 12).to_be_bytes();
+        let (ver, low) = (0x10 | (t >> 8) as u8 & 0xF, t as u8);
+        let b = self.0;
+        [
+            high[2], high[3], high[4], high[5], high[6], high[7], ver, low, //
+            b[8], b[9], b[10], b[11], b[12], b[13], b[14], b[15],
+        ]
+    }
+
+    /// Whether the key bytes in `order` are rearranged.
+    ///
+    /// The version and variant are in the same place either way.
+    #[inline]
+    const fn is_time_key(self, order: KeyOrder) -> bool {
+        matches!(order, KeyOrder::Time)
+            && matches!(self.variant(), Variant::Rfc4122)
+            && matches!(self.version(), Version::Time)
+    }
+
+    /// Return the UUID as the bytes of MySQL's `UUID_TO_BIN(uuid, swap)`.
+    ///
+    /// See [`Uuid::from_mysql_bin`] for details.
+    #[inline]
+    pub const fn to_mysql_bin(self, swap: bool) -> Bytes {
+        if !swap {
+            return self.to_bytes();
+        }
+        let b = self.0;
+        [
+            b[6], b[7], b[4], b[5], b[0], b[1], b[2], b[3], //
+            b[8], b[9], b[10], b[11], b[12], b[13], b[14], b[15],
+        ]
+    }
+
+    /// Return the UUID as a `u128`.
+    ///
+    /// See [`Uuid::from_u128`] for details.
+    #[inline]
+    pub const fn to_u128(self) -> u128 {
+        u128::from_be_bytes(self.0)
+    }
+
+    /// Return the UUID as an `i128`.
+    ///
+    /// See [`Uuid::from_i128`] for details.
+    #[inline]
+    pub const fn to_i128(self) -> i128 {
+        i128::from_be_bytes(self.0)
+    }
+
+    /// Return the high and low 64 bits of the UUID.
+    ///
+    /// See [`Uuid::from_u64_pair`] for details.
+    #[inline]
+    pub const fn to_u64_pair(self) -> (u64, u64) {
+        let n = self.to_u128();
+        ((n >> 64) as u64, n as u64)
+    }
+
+    /// Return the UUID as a little-endian `u128`.
+    ///
+    /// See [`Uuid::from_u128_le`] for details.
+    #[inline]
+    pub const fn to_u128_le(self) -> u128 {
+        u128::from_le_bytes(self.0)
+    }
+
+    /// Compress a UUID to a 32-bit Bluetooth UUID, if it is derived from the
+    /// [`BLUETOOTH_BASE_UUID`].
+    ///
+    /// Values that fit in 16 bits can be used as 16-bit Bluetooth UUIDs.
+    ///
+    /// See [`Uuid::from_bt_short`] for details.
+    #[inline]
+    pub const fn to_bt_short(self) -> Option<u32> {
+        let base = BLUETOOTH_BASE_UUID.to_u128() & (u128::MAX >> 32);
+        if self.to_u128() & (u128::MAX >> 32) == base {
+            Some(u32::from_be_bytes([
+                self.0[0], self.0[1], self.0[2], self.0[3],
+            ]))
+        } else {
+            None
+        }
+    }
+
+    /// Return the UUID as the classic RFC fields.
+    ///
+    /// See [`Uuid::from_fields`] for details.
+    #[inline]
+    pub const fn to_fields(self) -> (u32, u16, u16, [u8; 8]) {
+        let b = self.0;
+        (
+            u32::from_be_bytes([b[0], b[1], b[2], b[3]]),
+            u16::from_be_bytes([b[4], b[5]]),
+            u16::from_be_bytes([b[6], b[7]]),
+            [b[8], b[9], b[10], b[11], b[12], b[13], b[14], b[15]],
+        )
+    }
+
+    /// Return the UUID as mixed-endian fields.
+    ///
+    /// See [`Uuid::from_fields_me`] for details.
+    #[inline]
+    pub const fn to_fields_me(self) -> (u32, u16, u16, [u8; 8]) {
+        let (d1, d2, d3, d4) = self.to_fields();
+        (d1.swap_bytes(), d2.swap_bytes(), d3.swap_bytes(), d4)
+    }
+
+    /// Returns true if the UUID is nil.
+    #[inline]
+    pub const fn is_nil(self) -> bool {
+        // Done this way for const reasons
+        // nil is nil regardless of byte order
+        u128::from_ne_bytes(self.0) == 0
+    }
+
+    /// Compare the UUID to `s`, in any format supported by [`Uuid::parse`],
+    /// case insensitively.
+    ///
+    /// This is faster than parsing `s`, and does not allocate,
+    /// for comparing untrusted input, such as a request header,
+    /// to a known UUID.
+    ///
+    /// Invalid UUID strings are never equal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// assert!(uuid.eq_str("662AA7C7-7598-4D56-8BCC-A72C30F998A2"));
+    /// assert!(uuid.eq_str("{662aa7c7-7598-4d56-8bcc-a72c30f998a2}"));
+    /// assert!(!uuid.eq_str("00000000-0000-0000-0000-000000000000"));
+    /// ```
+    pub fn eq_str(self, s: &str) -> bool {
+        let mut buf = [0; UUID_STR_LENGTH];
+        let uuid = self.write_hyphenated(&mut buf, false).as_bytes();
+        let s = s.as_bytes();
+        match s.len() {
+            UUID_STR_LENGTH => s.eq_ignore_ascii_case(uuid),
+            UUID_BRACED_LENGTH => {
+                s.first() == Some(&b'{')
+                    && s.last() == Some(&b'}')
+                    && s[1..UUID_BRACED_LENGTH - 1].eq_ignore_ascii_case(uuid)
+            }
+            UUID_URN_LENGTH => {
+                s[..UUID_URN_PREFIX].eq_ignore_ascii_case(UUID_URN.as_bytes())
+                    && s[UUID_URN_PREFIX..].eq_ignore_ascii_case(uuid)
+            }
+            UUID_SIMPLE_LENGTH => s
+                .iter()
+                .zip(UUID_STR_DIGITS)
+                .all(|(c, i)| c.to_ascii_lowercase() == uuid[i as usize]),
+            _ => false,
+        }
+    }
+
+    /// Returns true if the UUID is max, where all bits are set to one.
+    ///
+    /// See RFC 9562 Section 5.10.
+    #[inline]
+    pub const fn is_max(self) -> bool {
+        u128::from_ne_bytes(self.0) == u128::MAX
+    }
+
+    /// Returns true if the UUID is the [`Variant::Rfc4122`] variant.
+    ///
+    /// The nil and max UUIDs are not.
+    #[inline]
+    pub const fn is_rfc(self) -> bool {
+        matches!(self.variant(), Variant::Rfc4122)
+    }
+
+    /// Returns true if the UUID is an RFC time based UUID,
+    /// Version 1, or with the `experimental_uuid` feature,
+    /// Version 6 or 7.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// assert!(Uuid::new_v1(0, 0, *b"world!").is_time_based());
+    /// assert!(!Uuid::new_v4_rng(&mut nuuid::Rng::from_seed([0; 32])).is_time_based());
+    /// ```
+    #[inline]
+    pub const fn is_time_based(self) -> bool {
+        if !self.is_rfc() {
+            return false;
+        }
+        match self.version() {
+            Version::Time => true,
+            #[cfg(feature = "experimental_uuid")]
+            Version::Database | Version::UnixTime => true,
+            _ => false,
+        }
+    }
+
+    /// Compare UUIDs for equality in constant time.
+    ///
+    /// Unlike `==`, this always compares every byte, so the time taken does
+    /// not reveal how many leading bytes matched.
+    /// Use this when comparing UUIDs used as secret tokens.
+    ///
+    /// This is best effort, see the `subtle` feature for
+    /// `subtle::ConstantTimeEq`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let token = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// assert!(token.ct_eq(token));
+    /// assert!(!token.ct_eq(Uuid::nil()));
+    /// ```
+    #[inline]
+    pub fn ct_eq<T: State>(self, other: Uuid<T>) -> bool {
+        let diff = self
+            .0
+            .iter()
+            .zip(other.0)
+            .fold(0, |acc, (a, b)| acc | core::hint::black_box(a ^ b));
+        core::hint::black_box(diff) == 0
+    }
+
+    /// Compare UUIDs the same as the Cassandra and ScyllaDB `timeuuid` type.
+    ///
+    /// This compares the version and timestamp first, then the clock
+    /// sequence and node as signed bytes.
+    ///
+    /// This matches the server side clustering order, unlike the
+    /// bytewise order of [`Uuid`]s `Ord`.
+    ///
+    /// See [`TimeUuidOrd`] for an `Ord` wrapper.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// # use core::cmp::Ordering;
+    /// let early = Uuid::new_v1(0xFFFF_FFFF, 0, [0; 6]);
+    /// let late = Uuid::new_v1(0x1_0000_0000, 0, [0; 6]);
+    /// assert_eq!(early.cmp_timeuuid(late), Ordering::Less);
+    /// assert_eq!(early.cmp(&late), Ordering::Greater);
+    ///
+    /// // Same timestamp, the node is compared as signed bytes
+    /// let neg = Uuid::new_v1(0, 0, [0x80, 0, 0, 0, 0, 0]);
+    /// let pos = Uuid::new_v1(0, 0, [0x7F, 0, 0, 0, 0, 0]);
+    /// assert_eq!(neg.cmp_timeuuid(pos), Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cmp_timeuuid<T: State>(self, other: Uuid<T>) -> Ordering {
+        let key = |b: Bytes| {
+            let msb = i64::from_be_bytes([b[6], b[7], b[4], b[5], b[0], b[1], b[2], b[3]]);
+            let mut lsb = [0i8; 8];
+            for (l, b) in lsb.iter_mut().zip(&b[8..]) {
+                *l = *b as i8;
+            }
+            (msb, lsb)
+        };
+        key(self.0).cmp(&key(other.0))
+    }
+
+    /// Compare UUIDs the same as the SQL Server `uniqueidentifier` type.
+    ///
+    /// SQL Server compares the last 6 bytes first, then the clock sequence,
+    /// then the remaining fields in their mixed-endian storage order,
+    /// see [`Uuid::from_bytes_me`].
+    ///
+    /// See [`SqlServerSequential`] for generating UUIDs in this order.
+    #[inline]
+    pub fn cmp_sql_server<T: State>(self, other: Uuid<T>) -> Ordering {
+        const ORDER: [usize; 16] = [10, 11, 12, 13, 14, 15, 8, 9, 7, 6, 5, 4, 3, 2, 1, 0];
+        let key = |b: Bytes| ORDER.map(|i| b[i]);
+        key(self.0).cmp(&key(other.0))
+    }
+
+    /// Compare UUIDs by their embedded timestamps, across versions.
+    ///
+    /// Timestamps are compared as 100-nanosecond intervals since the
+    /// Gregorian epoch, see [`Timestamp::to_gregorian_100ns`],
+    /// so a Version 1 UUID can be compared with a Version 7 UUID.
+    /// UUIDs at the same time are [`Ordering::Equal`],
+    /// regardless of their other bits.
+    ///
+    /// Returns [`None`] if either UUID is not time based,
+    /// see [`Uuid::is_time_based`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// # use core::cmp::Ordering;
+    /// let early = Uuid::new_v1(138788330336896890, 0, [0; 6]);
+    /// let late = Uuid::new_v1(138788330336896891, 0, [0; 6]);
+    /// assert_eq!(early.cmp_by_time(late), Some(Ordering::Less));
+    /// assert_eq!(early.cmp_by_time(Uuid::nil()), None);
+    /// ```
+    #[inline]
+    pub const fn cmp_by_time<T: State>(self, other: Uuid<T>) -> Option<Ordering> {
+        if !self.is_time_based() || !other.is_time_based() {
+            return None;
+        }
+        let a = self.timestamp_unchecked().to_gregorian_100ns();
+        let b = other.timestamp_unchecked().to_gregorian_100ns();
+        Some(if a < b {
+            Ordering::Less
+        } else if a > b {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        })
+    }
+
+    /// Combine two UUIDs by XOR of their bytes.
+    ///
+    /// This is commutative and its own inverse, so either UUID can be
+    /// recovered from the result and the other, and
+    /// `a.xor(b).xor(b) == a`.
+    ///
+    /// The result has no meaningful version or variant.
+    /// Because it is reversible, it reveals the relationship between
+    /// the inputs, use [`Uuid::derive_with`] where that matters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let tenant = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// let resource = Uuid::parse("5fc9fd3a-3b57-4a5a-95a5-fee2d64b5d2e").unwrap();
+    /// let id = tenant.xor(resource);
+    /// assert_eq!(id, resource.xor(tenant));
+    /// assert_eq!(id.xor(tenant), resource);
+    /// ```
+    #[inline]
+    pub const fn xor<T: State>(self, other: Uuid<T>) -> Uuid {
+        Uuid::from_u128(self.to_u128() ^ other.to_u128())
+    }
+
+    /// Derive a new Version 5 UUID from this one and `salt`.
+    ///
+    /// This is [`Uuid::new_v5`] using the UUID as the [`Namespace`],
+    /// so the result is deterministic, but unlike [`Uuid::xor`]
+    /// the inputs can't be recovered from it.
+    ///
+    /// Composite identifiers can be derived by using another UUID's bytes
+    /// as the salt.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Namespace, Uuid, Version};
+    /// let tenant = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// let resource = Uuid::parse("5fc9fd3a-3b57-4a5a-95a5-fee2d64b5d2e").unwrap();
+    /// let id = tenant.derive_with(&resource.to_bytes());
+    /// assert_eq!(id.version(), Version::Sha1);
+    /// assert_eq!(id, Uuid::new_v5(Namespace::new(tenant), &resource.to_bytes()));
+    /// ```
+    #[inline]
+    pub fn derive_with(self, salt: &[u8]) -> Uuid<RfcV5> {
+        Uuid::new_v5(Namespace::new(self), salt)
+    }
+
+    /// The position of the UUID on a consistent hash ring.
+    ///
+    /// This is the first 8 bytes XOR the last 8 bytes, each read as a
+    /// big-endian `u64`, and will not change, so other languages can place
+    /// the same UUID at the same point with one XOR.
+    ///
+    /// This is only well spread for random UUIDs, such as Version 4.
+    /// For time based UUIDs, hash the UUID to place it on the ring instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// assert_eq!(uuid.ring_position(), 0x662aa7c775984d56 ^ 0x8bcca72c30f998a2);
+    /// ```
+    #[inline]
+    pub const fn ring_position(self) -> u64 {
+        let (high, low) = self.to_u64_pair();
+        high ^ low
+    }
+
+    /// The next UUID, treating the UUID as a 128-bit big-endian integer,
+    /// or [`None`] if this is [`Uuid::MAX`].
+    ///
+    /// This is useful for exclusive upper bounds when scanning UUID keys,
+    /// which sort the same way.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998ff").unwrap();
+    /// let next = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f99900").unwrap();
+    /// assert_eq!(uuid.checked_add_one(), Some(next));
+    /// assert_eq!(Uuid::MAX.checked_add_one(), None);
+    /// ```
+    #[inline]
+    pub const fn checked_add_one(self) -> Option<Uuid> {
+        match self.to_u128().checked_add(1) {
+            Some(n) => Some(Uuid::from_u128(n)),
+            None => None,
+        }
+    }
+
+    /// The previous UUID, treating the UUID as a 128-bit big-endian integer,
+    /// or [`None`] if this is [`Uuid::NIL`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f99900").unwrap();
+    /// let prev = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998ff").unwrap();
+    /// assert_eq!(uuid.checked_sub_one(), Some(prev));
+    /// assert_eq!(Uuid::NIL.checked_sub_one(), None);
+    /// ```
+    #[inline]
+    pub const fn checked_sub_one(self) -> Option<Uuid> {
+        match self.to_u128().checked_sub(1) {
+            Some(n) => Some(Uuid::from_u128(n)),
+            None => None,
+        }
+    }
+
+    /// Classify the UUID into its typed state, see [`AnyUuid`].
+    ///
+    /// UUIDs that are not the [`Variant::Rfc4122`] variant, or are a
+    /// [`Version::Reserved`] version, are [`AnyUuid::NonRfc`].
+    #[inline]
+    pub const fn classify(self) -> AnyUuid {
+        if self.is_nil() {
+            return AnyUuid::Nil(self.cast());
+        }
+        #[cfg(feature = "experimental_uuid")]
+        if self.is_max() {
+            return AnyUuid::Max(self.cast());
+        }
+        match (self.variant(), self.version()) {
+            (Variant::Rfc4122, Version::Time) => AnyUuid::V1(self.cast()),
+            (Variant::Rfc4122, Version::Dce) => AnyUuid::V2(self.cast()),
+            (Variant::Rfc4122, Version::Md5) => AnyUuid::V3(self.cast()),
+            (Variant::Rfc4122, Version::Random) => AnyUuid::V4(self.cast()),
+            (Variant::Rfc4122, Version::Sha1) => AnyUuid::V5(self.cast()),
+            #[cfg(feature = "experimental_uuid")]
+            (Variant::Rfc4122, Version::Database) => AnyUuid::V6(self.cast()),
+            #[cfg(feature = "experimental_uuid")]
+            (Variant::Rfc4122, Version::UnixTime) => AnyUuid::V7(self.cast()),
+            #[cfg(feature = "experimental_uuid")]
+            (Variant::Rfc4122, Version::Vendor) => AnyUuid::V8(self.cast()),
+            _ => AnyUuid::NonRfc(self.cast()),
+        }
+    }
+
+    /// The UUID Variant
+    ///
+    /// # Warning
+    ///
+    /// Many UUIDs out in the wild are incorrectly generated,
+    /// so this value can't be relied upon.
+    #[inline]
+    pub const fn variant(self) -> Variant {
+        // Check the highest 3 bits, skipping the 5 bits of the time/clock sequence
+        let byte = (self.0[8] >> 5) & 0b111;
+
+        // Done this way for exhaustiveness checking.
+        match (
+            // Msb0
+            byte >> 2 & 1 == 1,
+            // Msb1
+            byte >> 1 & 1 == 1,
+            // Msb2
+            byte & 1 == 1,
+        ) {
+            (false, ..) => Variant::Ncs,
+            (true, false, ..) => Variant::Rfc4122,
+            (true, true, false) => Variant::Microsoft,
+            (true, true, true) => Variant::Reserved,
+        }
+    }
+
+    /// The UUID Version
+    ///
+    /// For "incorrect" or non-RFC UUIDs, this value may be nonsensical.
+    ///
+    /// If the version bits do not match any known version,
+    /// [`Version::Reserved`] is returned instead.
+    /// As new versions are recognized, this may change.
+    ///
+    /// # Warning
+    ///
+    /// Many UUIDs out in the wild are incorrectly generated,
+    /// so this value can't be relied upon.
+    #[inline]
+    pub const fn version(self) -> Version {
+        // Check the highest 4 bits
+        match (
+            self.0[6] >> 7 & 1 == 1,
+            self.0[6] >> 6 & 1 == 1,
+            self.0[6] >> 5 & 1 == 1,
+            self.0[6] >> 4 & 1 == 1,
+        ) {
+            (false, false, false, false) => Version::Nil,
+            (false, false, false, true) => Version::Time,
+            (false, false, true, false) => Version::Dce,
+            (false, false, true, true) => Version::Md5,
+            (false, true, false, false) => Version::Random,
+            (false, true, false, true) => Version::Sha1,
+
+            #[cfg(feature = "experimental_uuid")]
+            (false, true, true, false) => Version::Database,
+
+            #[cfg(feature = "experimental_uuid")]
+            (false, true, true, true) => Version::UnixTime,
+
+            #[cfg(feature = "experimental_uuid")]
+            (true, false, false, false) => Version::Vendor,
+
+            _ => Version::Reserved,
+        }
+    }
+
+    /// The UUID timestamp, see [`Uuid::timestamp`].
+    ///
+    /// Versions without a timestamp are treated as [`Version::Time`].
+    #[inline]
+    const fn timestamp_unchecked(self) -> Timestamp {
+        match self.version() {
+            #[cfg(feature = "experimental_uuid")]
+            Version::Database => Timestamp {
+                version: Version::Database,
+                ticks: (u32::from_be_bytes([self.0[0], self.0[1], self.0[2], self.0[3]]) as u64)
+                    << 28
+                    | (u16::from_be_bytes([self.0[4], self.0[5]]) as u64) << 12
+                    // Clear version bits
+                    | ((self.0[6] & 0xF) as u64) << 8
+                    | self.0[7] as u64,
+            },
+            #[cfg(feature = "experimental_uuid")]
+            Version::UnixTime => Timestamp {
+                version: Version::UnixTime,
+                ticks: u64::from_be_bytes([
+                    0, 0, self.0[0], self.0[1], self.0[2], self.0[3], self.0[4], self.0[5],
+                ]),
+            },
+            _ => Timestamp {
+                version: Version::Time,
+                ticks: u64::from_be_bytes([
+                    // Clear version bits
+                    self.0[6] & 0xF,
+                    self.0[7],
+                    self.0[4],
+                    self.0[5],
+                    self.0[0],
+                    self.0[1],
+                    self.0[2],
+                    self.0[3],
+                ]),
+            },
+        }
+    }
+
+    /// The 48-bit unix millisecond timestamp
+    ///
+    /// This is only present in [`Version::UnixTime`] UUIDs,
+    /// and will be [`None`] otherwise.
+    ///
+    /// This is the time-ordered prefix of the UUID, and is suitable for
+    /// time-bucketing, TTLs, and partitioning.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::new_v7(0x17F22E279B0, 0, 0);
+    /// assert_eq!(uuid.unix_ts_ms(), Some(0x17F22E279B0));
+    /// ```
+    #[inline]
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    pub const fn unix_ts_ms(self) -> Option<u64> {
+        match self.version() {
+            Version::UnixTime => Some(u64::from_be_bytes([
+                0, 0, self.0[0], self.0[1], self.0[2], self.0[3], self.0[4], self.0[5],
+            ])),
+            _ => None,
+        }
+    }
+
+    /// The UUID clock sequence, see [`Uuid::clock_sequence`].
+    #[inline]
+    const fn clock_sequence_unchecked(self) -> Option<ClockSeq> {
+        // Clear variant bits
+        // Only need to clear two because this only makes sense for RFC UUIDs
+        let hi = self.0[8] & 0x3F;
+        match self.version() {
+            Version::Time => Some(ClockSeq(u16::from_be_bytes([hi, self.0[9]]))),
+            Version::Dce => Some(ClockSeq(u16::from_be_bytes([hi, 0]))),
+            #[cfg(feature = "experimental_uuid")]
+            Version::Database => Some(ClockSeq(u16::from_be_bytes([hi, self.0[9]]))),
+            _ => None,
+        }
+    }
+
+    /// The DCE Security domain
+    ///
+    /// This is only present in [`Version::Dce`] UUIDs,
+    /// and will be [`None`] otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{DceDomain, Uuid};
+    /// let uuid = Uuid::parse("000003e8-0a1e-21ef-8900-325096b39f47").unwrap();
+    /// assert_eq!(uuid.dce_domain(), Some(DceDomain::Person));
+    /// assert_eq!(uuid.dce_local_id(), Some(1000));
+    /// ```
+    #[inline]
+    pub const fn dce_domain(self) -> Option<DceDomain> {
+        match self.version() {
+            Version::Dce => Some(DceDomain::from_byte(self.0[9])),
+            _ => None,
+        }
+    }
+
+    /// The DCE Security local ID
+    ///
+    /// This is a UID, GID, or other ID depending on [`Uuid::dce_domain`].
+    ///
+    /// This is only present in [`Version::Dce`] UUIDs,
+    /// and will be [`None`] otherwise.
+    #[inline]
+    pub const fn dce_local_id(self) -> Option<u32> {
+        match self.version() {
+            Version::Dce => Some(u32::from_be_bytes([
+                self.0[0], self.0[1], self.0[2], self.0[3],
+            ])),
+            _ => None,
+        }
+    }
+
+    /// The 48-bit UUID Node ID
+    ///
+    /// This is only present in [`Version::Time`], [`Version::Dce`], and
+    /// [`Version::Database`] UUIDs, and will be [`None`] otherwise.
+    ///
+    /// For [`Version::Time`] UUIDs, this is usually a MAC address.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::new_v1(0, 0, *b"world!");
+    /// assert_eq!(uuid.node(), Some(*b"world!"));
+    /// # #[cfg(feature = "getrandom")]
+    /// assert_eq!(Uuid::new_v4().node(), None);
+    /// ```
+    #[inline]
+    pub const fn node(self) -> Option<[u8; 6]> {
+        match self.version() {
+            Version::Time | Version::Dce => Some(self.node_unchecked()),
+            #[cfg(feature = "experimental_uuid")]
+            Version::Database => Some(self.node_unchecked()),
+            _ => None,
+        }
+    }
+
+    /// The 48-bit UUID Node ID, regardless of version
+    #[inline]
+    const fn node_unchecked(self) -> [u8; 6] {
+        [
+            self.0[10], self.0[11], self.0[12], self.0[13], self.0[14], self.0[15],
+        ]
+    }
+
+    /// Decode all available information about the UUID.
+    ///
+    /// This is intended for inspection tools and debugging,
+    /// see [`UuidInfo`] for details.
+    #[inline]
+    pub const fn decode(self) -> UuidInfo {
+        let timestamp = match self.version() {
+            Version::Time => Some(self.timestamp_unchecked()),
+            #[cfg(feature = "experimental_uuid")]
+            Version::Database | Version::UnixTime => Some(self.timestamp_unchecked()),
+            _ => None,
+        };
+        UuidInfo {
+            uuid: self.cast(),
+            variant: self.variant(),
+            version: self.version(),
+            timestamp,
+            node: self.node(),
+            clock_seq: self.clock_sequence_unchecked(),
+        }
+    }
+
+    /// Write UUID as a lowercase ASCII string into `buf`, and returns it as a
+    /// string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use nuuid::Uuid;
+    /// const EXAMPLE_UUID: &str = "662aa7c7-7598-4d56-8bcc-a72c30f998a2";
+    /// let uuid = Uuid::parse(EXAMPLE_UUID)?;
+    ///
+    /// let mut buf = [0u8; 36];
+    /// let string = uuid.to_str(&mut buf);
+    /// assert_eq!(string, EXAMPLE_UUID);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// With an array
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// let mut buf = [0u8; 36];
+    /// let string = uuid.to_str(&mut buf);
+    /// ```
+    ///
+    /// With a slice
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// # use std::convert::TryInto;
+    /// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// let mut data = [0u8; 50];
+    /// let string = uuid.to_str((&mut data[..36]).try_into().unwrap());
+    /// ```
+    ///
+    /// With a slice, incorrectly.
+    ///
+    /// The problem here is that the slices length is unconstrained, and could
+    /// be more or less than 36.
+    ///
+    /// ```rust,should_panic
+    /// # use nuuid::Uuid;
+    /// # use std::convert::TryInto;
+    /// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// let mut data = [0u8; 50];
+    /// let string = uuid.to_str((&mut data[..]).try_into().unwrap());
+    /// ```
+    ///
+    /// Use [`Uuid::try_to_str`] for slices instead.
+    #[cfg_attr(feature = "no-panic", no_panic::no_panic)]
+    pub fn to_str(self, buf: &mut [u8; 36]) -> &mut str {
+        self.write_hyphenated(buf, false)
+    }
+
+    /// Write a UUID as a lowercase ASCII string into `buf`, and return it as a
+    /// string.
+    ///
+    /// For usage examples see [`Uuid::to_str`].
+    #[inline]
+    #[cfg_attr(feature = "no-panic", no_panic::no_panic)]
+    pub fn to_urn(self, buf: &mut [u8; 45]) -> &mut str {
+        let mut s = [0; UUID_STR_LENGTH];
+        self.to_str(&mut s);
+        buf[..UUID_URN_PREFIX].copy_from_slice(UUID_URN.as_bytes());
+        buf[UUID_URN_PREFIX..].copy_from_slice(&s);
+
+        debug_assert!(buf.is_ascii(), "BUG: Invalid ASCII in nuuid::Uuid::to_urn");
+        // Safety: Fully initialized with ASCII
+        unsafe { from_utf8_unchecked_mut(buf) }
+    }
+
+    /// [`Uuid::to_str`], but uppercase.
+    #[inline]
+    #[cfg_attr(feature = "no-panic", no_panic::no_panic)]
+    pub fn to_str_upper(self, buf: &mut [u8; 36]) -> &mut str {
+        self.write_hyphenated(buf, true)
+    }
+
+    /// Display only the start of the UUID, for logging identifiers that
+    /// correlate to users.
+    ///
+    /// This shows the first 8 hex digits by default,
+    /// see [`Redacted::prefix`].
+    ///
+    /// See [`SecretUuid`] for a UUID that is always redacted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse("936da01f-9abd-4d9d-80c7-02af85c822a8").unwrap();
+    /// assert_eq!(uuid.redacted().to_string(), "936da01f-****");
+    /// assert_eq!(uuid.redacted().prefix(4).to_string(), "936d****");
+    /// ```
+    #[inline]
+    pub const fn redacted(self) -> Redacted {
+        Redacted {
+            uuid: self.erase(),
+            prefix: 8,
+        }
+    }
+
+    /// Write the hyphenated UUID into `buf`, see [`HEX_ENCODER`].
+    #[inline]
+    fn write_hyphenated(self, buf: &mut [u8; 36], upper: bool) -> &mut str {
+        let n = u128::from_be_bytes(self.0);
+        let hi = encode_hex((n >> 64) as u64, upper);
+        let lo = encode_hex(n as u64, upper);
+
+        // time_low
+        buf[..8].copy_from_slice(&hi[..8]);
+        buf[8] = b'-';
+        // time_mid
+        buf[9..13].copy_from_slice(&hi[8..12]);
+        buf[13] = b'-';
+        // time_hi_and_version
+        buf[14..18].copy_from_slice(&hi[12..]);
+        buf[18] = b'-';
+        // clock_seq_hi_and_reserved and clock_seq_low
+        buf[19..23].copy_from_slice(&lo[..4]);
+        buf[23] = b'-';
+        // node
+        buf[24..].copy_from_slice(&lo[4..]);
+
+        debug_assert!(buf.is_ascii(), "BUG: Invalid ASCII in nuuid::Uuid::to_str");
+        // This is consistently faster than using the safe checked variant.
+        // Safety: Fully initialized with ASCII hex
+        unsafe { from_utf8_unchecked_mut(buf) }
+    }
+
+    /// [`Uuid::to_str`], but writing to the start of a slice of any length.
+    ///
+    /// # Errors
+    ///
+    /// - [`NuuidError::BufferTooSmall`] if `buf` is shorter than 36 bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NuuidError, Uuid};
+    /// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// let mut data = [0u8; 50];
+    /// assert_eq!(
+    ///     uuid.try_to_str(&mut data).unwrap(),
+    ///     "662aa7c7-7598-4d56-8bcc-a72c30f998a2"
+    /// );
+    /// assert_eq!(uuid.try_to_str(&mut data[..35]), Err(NuuidError::BufferTooSmall));
+    /// ```
+    #[inline]
+    #[cfg_attr(feature = "no-panic", no_panic::no_panic)]
+    pub fn try_to_str(self, buf: &mut [u8]) -> Result<&mut str, NuuidError> {
+        match buf.first_chunk_mut() {
+            Some(buf) => Ok(self.to_str(buf)),
+            None => Err(NuuidError::BufferTooSmall),
+        }
+    }
+
+    /// [`Uuid::try_to_str`], but uppercase.
+    ///
+    /// # Errors
+    ///
+    /// - [`NuuidError::BufferTooSmall`] if `buf` is shorter than 36 bytes.
+    #[inline]
+    #[cfg_attr(feature = "no-panic", no_panic::no_panic)]
+    pub fn try_to_str_upper(self, buf: &mut [u8]) -> Result<&mut str, NuuidError> {
+        match buf.first_chunk_mut() {
+            Some(buf) => Ok(self.to_str_upper(buf)),
+            None => Err(NuuidError::BufferTooSmall),
+        }
+    }
+
+    /// [`Uuid::to_urn`], but the UUID is uppercase.
+    #[inline]
+    #[cfg_attr(feature = "no-panic", no_panic::no_panic)]
+    pub fn to_urn_upper(self, buf: &mut [u8; 45]) -> &mut str {
+        let s = self.to_urn(buf);
+        s[UUID_URN_PREFIX..].make_ascii_uppercase();
+        s
+    }
+
+    /// Write the UUID as an uppercase [ULID][ulid] string into `buf`, and
+    /// return it as a string.
+    ///
+    /// ULIDs share the same 128-bit layout as UUIDs, and are encoded using
+    /// Crockford's Base32.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse("01890a5d-ac96-774b-bcce-b302099a8057").unwrap();
+    /// let mut buf = [0u8; 26];
+    /// assert_eq!(uuid.to_ulid(&mut buf), "01H455VB4PEX5VSKNK084SN02Q");
+    /// ```
+    ///
+    /// [ulid]: https://github.com/ulid/spec
+    pub fn to_ulid(self, buf: &mut [u8; 26]) -> &mut str {
+        let n = u128::from_be_bytes(self.0);
+        for (i, c) in buf.iter_mut().rev().enumerate() {
+            *c = CROCKFORD[((n >> (i * 5)) & 0x1F) as usize];
+        }
+
+        debug_assert!(buf.is_ascii(), "BUG: Invalid ASCII in nuuid::Uuid::to_ulid");
+        // Safety: Fully initialized with ASCII
+        unsafe { from_utf8_unchecked_mut(buf) }
+    }
+
+    /// Write the UUID as a Rust expression into `buf`, and return it as a
+    /// string.
+    ///
+    /// This is intended for code generators, and produces a `const`
+    /// compatible [`uuid!`] call, which must be in scope.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NAMESPACE_DNS, Uuid};
+    /// let mut buf = [0u8; Uuid::RUST_LITERAL_LEN];
+    /// assert_eq!(
+    ///     NAMESPACE_DNS.get().as_rust_literal(&mut buf),
+    ///     r#"uuid!("6ba7b810-9dad-11d1-80b4-00c04fd430c8")"#
+    /// );
+    /// ```
+    pub fn as_rust_literal<'a>(&self, buf: &'a mut [u8; UUID_RUST_LENGTH]) -> &'a mut str {
+        let prefix = UUID_RUST_PREFIX.len();
+        buf[..prefix].copy_from_slice(UUID_RUST_PREFIX.as_bytes());
+        let _ = self.to_str(
+            (&mut buf[prefix..prefix + UUID_STR_LENGTH])
+                .try_into()
+                .unwrap(),
+        );
+        buf[UUID_RUST_LENGTH - 2..].copy_from_slice(b"\")");
+
+        debug_assert!(
+            buf.is_ascii(),
+            "BUG: Invalid ASCII in nuuid::Uuid::as_rust_literal"
+        );
+        // Safety: Fully initialized with ASCII
+        unsafe { from_utf8_unchecked_mut(buf) }
+    }
+
+    /// Write the UUID as a C array initializer into `buf`, and return it as a
+    /// string.
+    ///
+    /// The bytes are written in the same order as [`Uuid::to_bytes`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NAMESPACE_DNS, Uuid};
+    /// let mut buf = [0u8; Uuid::C_ARRAY_LEN];
+    /// assert_eq!(
+    ///     NAMESPACE_DNS.get().as_c_array(&mut buf),
+    ///     "{0x6b,0xa7,0xb8,0x10,0x9d,0xad,0x11,0xd1,0x80,0xb4,0x00,0xc0,0x4f,0xd4,0x30,0xc8}"
+    /// );
+    /// ```
+    pub fn as_c_array<'a>(&self, buf: &'a mut [u8; UUID_C_LENGTH]) -> &'a mut str {
+        buf[0] = b'{';
+        self.write_byte_list(&mut buf[1..UUID_C_LENGTH - 1], b",");
+        buf[UUID_C_LENGTH - 1] = b'}';
+
+        debug_assert!(
+            buf.is_ascii(),
+            "BUG: Invalid ASCII in nuuid::Uuid::as_c_array"
+        );
+        // Safety: Fully initialized with ASCII
+        unsafe { from_utf8_unchecked_mut(buf) }
+    }
+
+    /// Convert a Version 1 UUID to a Version 6 UUID.
+    ///
+    /// This only reorders the timestamp, and is lossless.
+    /// The clock sequence, variant, and node are copied unchanged.
+    ///
+    /// Returns [`None`] if this is not a [`Version::Time`] UUID.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Uuid, Version};
+    /// let v1 = Uuid::new_v1(138648505420000000, 13256, [0; 6]);
+    /// let v6 = v1.to_v6().unwrap();
+    /// assert_eq!(v6.version(), Version::Database);
+    /// assert_eq!(v6.timestamp().to_gregorian_100ns(), 138648505420000000);
+    /// assert_eq!(v6.to_v1(), Some(v1));
+    /// ```
+    #[inline]
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    pub fn to_v6(self) -> Option<Uuid<RfcV6>> {
+        match self.version() {
+            Version::Time => {
+                let mut uuid =
+                    Uuid::new_v6(self.timestamp_unchecked().to_gregorian_100ns(), 0, [0; 6]);
+                uuid.0[8..].copy_from_slice(&self.0[8..]);
+                Some(uuid)
+            }
+            _ => None,
+        }
+    }
+
+    /// Convert a Version 6 UUID to a Version 1 UUID.
+    ///
+    /// Returns [`None`] if this is not a [`Version::Database`] UUID.
+    ///
+    /// See [`Uuid::to_v6`] for details.
+    #[inline]
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    pub fn to_v1(self) -> Option<Uuid<RfcV1>> {
+        match self.version() {
+            Version::Database => {
+                let mut uuid =
+                    Uuid::new_v1(self.timestamp_unchecked().to_gregorian_100ns(), 0, [0; 6]);
+                uuid.0[8..].copy_from_slice(&self.0[8..]);
+                Some(uuid)
+            }
+            _ => None,
+        }
+    }
+
+    /// Convert a Version 1 or 6 UUID to an approximately equivalent
+    /// Version 7 UUID.
+    ///
+    /// The timestamp is converted to unix milliseconds, and the rest of the
+    /// UUID is filled with randomness from `rng`.
+    ///
+    /// This is **not** reversible, sub-millisecond precision, the clock
+    /// sequence, and the node are all lost.
+    /// It does however preserve creation time ordering, to the millisecond.
+    ///
+    /// Returns [`None`] if this is not a [`Version::Time`] or
+    /// [`Version::Database`] UUID.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Rng, Uuid, Version};
+    /// # let mut rng = Rng::from_seed([0; 32]);
+    /// let v1 = Uuid::new_v1(138788330336896890, 0, [0; 6]);
+    /// let v7 = v1.to_v7_approx(&mut rng).unwrap();
+    /// assert_eq!(v7.version(), Version::UnixTime);
+    /// assert_eq!(v7.unix_ts_ms(), Some(1659540233689));
+    /// ```
+    #[inline]
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    pub fn to_v7_approx(self, rng: &mut Rng) -> Option<Uuid<RfcV7>> {
+        match self.version() {
+            Version::Time | Version::Database => {
+                let ticks = self
+                    .timestamp_unchecked()
+                    .to_gregorian_100ns()
+                    .saturating_sub(GREGORIAN_OFFSET);
+                let mut rand = [0; 10];
+                rng.fill_bytes(&mut rand);
+                Some(Uuid::new_v7(
+                    ticks / 10_000,
+                    u16::from_be_bytes([rand[0], rand[1]]),
+                    u64::from_be_bytes([
+                        rand[2], rand[3], rand[4], rand[5], rand[6], rand[7], rand[8], rand[9],
+                    ]),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// The 64-bit Snowflake ID
+    ///
+    /// This value will only make sense for UUIDs created by
+    /// [`Uuid::new_v8_snowflake`]
+    #[inline]
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    pub const fn to_snowflake(self) -> u64 {
+        let b = self.0;
+        u64::from_be_bytes([
+            b[0],
+            b[1],
+            b[2],
+            b[3],
+            b[4],
+            b[5],
+            (b[6] << 4) | (b[7] >> 4),
+            (b[7] << 4) | (b[8] & 0xF),
+        ])
+    }
+
+    /// Write the UUID as an escaped LDAP filter value for `objectGUID` into
+    /// `buf`, and return it as a string.
+    ///
+    /// Each byte of [`Uuid::to_ad_object_guid`] is written as `\NN`, as
+    /// required to search for binary attributes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// let mut buf = [0u8; 48];
+    /// assert_eq!(
+    ///     uuid.as_ldap_filter(&mut buf),
+    ///     r"\c7\a7\2a\66\98\75\56\4d\8b\cc\a7\2c\30\f9\98\a2"
+    /// );
+    /// ```
+    pub fn as_ldap_filter<'a>(&self, buf: &'a mut [u8; 48]) -> &'a mut str {
+        for (out, byte) in buf.chunks_exact_mut(3).zip(self.to_ad_object_guid()) {
+            out[0] = b'\\';
+            out[1] = HEX_LOWER[(byte >> 4) as usize];
+            out[2] = HEX_LOWER[(byte & 0xF) as usize];
+        }
+
+        debug_assert!(
+            buf.is_ascii(),
+            "BUG: Invalid ASCII in nuuid::Uuid::as_ldap_filter"
+        );
+        // Safety: Fully initialized with ASCII
+        unsafe { from_utf8_unchecked_mut(buf) }
+    }
+
+    /// Write each byte as `0xNN`, separated by `sep`.
+    ///
+    /// `buf` must be exactly large enough.
+    #[inline]
+    fn write_byte_list(&self, buf: &mut [u8], sep: &[u8]) {
+        let step = 4 + sep.len();
+        debug_assert_eq!(buf.len(), (16 * step) - sep.len());
+        for (i, byte) in self.0.iter().enumerate() {
+            let out = &mut buf[i * step..];
+            out[0] = b'0';
+            out[1] = b'x';
+            out[2] = HEX_LOWER[(byte >> 4) as usize];
+            out[3] = HEX_LOWER[(byte & 0xF) as usize];
+            if i != 15 {
+                out[4..step].copy_from_slice(sep);
+            }
+        }
+    }
+}
+
+/// Time based UUIDs, see [`HasTimestamp`].
+impl<S: HasTimestamp> Uuid<S> {
+    /// The UUID timestamp
+    ///
+    /// This is only available for time based UUIDs,
+    /// see [`HasTimestamp`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Uuid, Version};
+    /// let uuid = Uuid::new_v1(138788330336896890, 0, [0; 6]);
+    /// let time = uuid.timestamp();
+    /// assert_eq!(time.version(), Version::Time);
+    /// assert_eq!(time.to_gregorian_100ns(), 138788330336896890);
+    /// assert_eq!(time.to_unix(), (1659540233, 689689000));
+    /// ```
+    #[inline]
+    pub const fn timestamp(self) -> Timestamp {
+        self.timestamp_unchecked()
+    }
+
+    /// The UUID clock sequence
+    ///
+    /// This is only present in [`Version::Time`] and [`Version::Database`]
+    /// UUIDs, and will be [`None`] for [`Version::UnixTime`] UUIDs.
+    ///
+    /// Use [`Uuid::decode`] to get the clock sequence of
+    /// [`Version::Dce`] UUIDs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::new_v1(0, 8648, [0; 6]);
+    /// assert_eq!(uuid.clock_sequence().unwrap().get(), 8648);
+    /// ```
+    #[inline]
+    pub const fn clock_sequence(self) -> Option<ClockSeq> {
+        self.clock_sequence_unchecked()
+    }
+
+    /// The UUID timestamp as a [`SystemTime`](std::time::SystemTime),
+    /// or [`None`] if the platform can't represent it.
+    ///
+    /// See [`Uuid::timestamp`] and [`Timestamp::to_system_time`] for details.
+    #[inline]
+    #[cfg(any(test, feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn time_as_system_time(self) -> Option<std::time::SystemTime> {
+        self.timestamp().to_system_time()
+    }
+}
+
+impl Uuid {
+    /// Parse a [`Uuid`] from a string
+    ///
+    /// This method is case insensitive and supports the following formats:
+    ///
+    /// - `urn:uuid:` `urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2`
+    /// - "Braced" `{662aa7c7-7598-4d56-8bcc-a72c30f998a2}`
+    /// - "Hyphenate" `662aa7c7-7598-4d56-8bcc-a72c30f998a2`
+    /// - "Simple" `662aa7c775984d568bcca72c30f998a2`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// Uuid::parse("662AA7C7-7598-4D56-8BCC-A72C30F998A2").unwrap();
+    ///
+    /// Uuid::parse("urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+    /// Uuid::parse("urn:uuid:662AA7C7-7598-4D56-8BCC-A72C30F998A2").unwrap();
+    ///
+    /// Uuid::parse("662aa7c775984d568bcca72c30f998a2").unwrap();
+    /// Uuid::parse("662AA7C775984D568BCCA72C30F998A2").unwrap();
+    ///
+    /// Uuid::parse("{662aa7c7-7598-4d56-8bcc-a72c30f998a2}").unwrap();
+    /// Uuid::parse("{662AA7C7-7598-4D56-8BCC-A72C30F998A2}").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - If `s` is not a valid UUID, see [`ParseUuidError`] for details.
+    #[cfg_attr(feature = "no-panic", no_panic::no_panic)]
+    pub fn parse(s: &str) -> Result<Self, ParseUuidError> {
+        let s = s.as_bytes();
+        let format = match s.len() {
+            UUID_URN_LENGTH => UuidFormat::Urn,
+            UUID_BRACED_LENGTH => UuidFormat::Braced,
+            UUID_STR_LENGTH => UuidFormat::Hyphenated,
+            UUID_SIMPLE_LENGTH => UuidFormat::Simple,
+            len => return Err(ParseUuidError::invalid_length(len, None)),
+        };
+        Uuid::parse_format(s, format).ok_or_else(|| ParseUuidError::find(s, format))
+    }
+
+    /// Parse `s` as `format`, which must match its length.
+    ///
+    /// This is the fast path, see [`ParseUuidError::find`] for errors.
+    #[inline]
+    fn parse_format(s: &[u8], format: UuidFormat) -> Option<Self> {
+        let s: &[u8; UUID_STR_LENGTH] = match format {
+            UuidFormat::Urn => {
+                if !s
+                    .get(..UUID_URN_PREFIX)?
+                    .eq_ignore_ascii_case(UUID_URN.as_bytes())
+                {
+                    return None;
+                }
+                s.last_chunk()?
+            }
+            UuidFormat::Braced => {
+                if s.first() != Some(&b'{') || s.last() != Some(&b'}') {
+                    return None;
+                }
+                s.get(1..)?.first_chunk()?
+            }
+            UuidFormat::Hyphenated => s.first_chunk()?,
+            UuidFormat::Simple => {
+                let mut raw: [u8; UUID_SIMPLE_LENGTH] = *s.first_chunk()?;
+                let x = decode_inplace(&mut raw).ok()?;
+                return Some(Uuid::from_bytes(x.try_into().ok()?));
+            }
+            UuidFormat::Ulid => return None,
+        };
+        if s[8] != b'-' || s[13] != b'-' || s[18] != b'-' || s[23] != b'-' {
+            return None;
+        }
+
+        let mut raw = [0; UUID_SIMPLE_LENGTH];
+        // "00000000-0000-0000-0000-000000000000"
+        //          9    14   19   24
+        // - 1
+        // "00000000000000000000000000000000"
+        //          9   13  17  21
+        // - 1
+
+        // Copy the UUID to `raw`, but without the hyphens, so we can
+        // decode it in-place.
+        // Benchmarking showed this was much faster than decoding directly
+        // to raw in-between the hyphens.
+
+        // Node data
+        raw[20..].copy_from_slice(&s[24..]);
+
+        // High bits of the clock, variant, and low bits of the clock
+        raw[16..20].copy_from_slice(&s[19..23]);
+
+        // High bits of the timestamp, and version
+        raw[12..16].copy_from_slice(&s[14..18]);
+
+        // Middle bits of the timestamp
+        raw[8..12].copy_from_slice(&s[9..13]);
+
+        // Low bits of the timestamp
+        raw[..8].copy_from_slice(&s[..8]);
+
+        let x = decode_inplace(&mut raw).ok()?;
+        Some(Uuid::from_bytes(x.try_into().ok()?))
+    }
+
+    /// Parse a [`Uuid`] in a const context.
+    ///
+    /// This supports the same formats as [`Uuid::parse`], and is case
+    /// insensitive, but is slower, so prefer [`Uuid::parse`] at runtime.
+    ///
+    /// See the [`uuid!`] macro for UUID literals.
+    ///
+    /// # Errors
+    ///
+    /// - If `s` is not a valid UUID, see [`ParseUuidError`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// const UUID: Uuid = match Uuid::parse_const("662aa7c7-7598-4d56-8bcc-a72c30f998a2") {
+    ///     Ok(uuid) => uuid,
+    ///     Err(_) => panic!("Invalid UUID"),
+    /// };
+    /// assert_eq!(UUID, Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap());
+    /// ```
+    pub const fn parse_const(s: &str) -> Result<Self, ParseUuidError> {
+        let s = s.as_bytes();
+        // Where the hyphenated UUID starts, if any.
+        let (format, start) = match s.len() {
+            UUID_URN_LENGTH => (UuidFormat::Urn, Some(UUID_URN_PREFIX)),
+            UUID_BRACED_LENGTH => (UuidFormat::Braced, Some(1)),
+            UUID_STR_LENGTH => (UuidFormat::Hyphenated, Some(0)),
+            UUID_SIMPLE_LENGTH => (UuidFormat::Simple, None),
+            len => return Err(ParseUuidError::invalid_length(len, None)),
+        };
+        // `find` checks the prefix, braces, and hyphens, so only check that
+        // anything is wrong here.
+        let valid = match format {
+            UuidFormat::Urn => {
+                let mut valid = true;
+                let mut i = 0;
+                while i < UUID_URN_PREFIX {
+                    valid &= s[i].to_ascii_lowercase() == UUID_URN.as_bytes()[i];
+                    i += 1;
+                }
+                valid
+            }
+            UuidFormat::Braced => s[0] == b'{' && s[UUID_BRACED_LENGTH - 1] == b'}',
+            _ => true,
+        };
+        let valid = match start {
+            Some(o) => {
+                valid
+                    && s[o + 8] == b'-'
+                    && s[o + 13] == b'-'
+                    && s[o + 18] == b'-'
+                    && s[o + 23] == b'-'
+            }
+            None => valid,
+        };
+        if !valid {
+            return Err(ParseUuidError::find(s, format));
+        }
+
+        let mut bytes: Bytes = [0; 16];
+        // Invalid digits are `0xFF`, so check them all at once at the end.
+        let mut invalid = 0;
+        let mut i = 0;
+        while i < 16 {
+            let (hi, lo) = match start {
+                Some(o) => (
+                    o + UUID_STR_DIGITS[i * 2] as usize,
+                    o + UUID_STR_DIGITS[i * 2 + 1] as usize,
+                ),
+                None => (i * 2, i * 2 + 1),
+            };
+            let hi = HEX_DECODE[s[hi] as usize];
+            let lo = HEX_DECODE[s[lo] as usize];
+            invalid |= hi | lo;
+            bytes[i] = (hi << 4) | (lo & 0xF);
+            i += 1;
+        }
+        if invalid > 0xF {
+            return Err(ParseUuidError::find(s, format));
+        }
+        Ok(Uuid::from_bytes(bytes))
+    }
+
+    /// Check that `self` is the version and variant of `T`,
+    /// in a const context.
+    ///
+    /// Used by the version checked literal macros, such as [`uuid_v4!`],
+    /// and constructors, such as [`Uuid::from_bytes_v4`].
+    /// This does not check `V8Layout`s, and should not be used for them.
+    #[doc(hidden)]
+    pub const fn __const_versioned<T: state::VersionedUuid>(self) -> Result<Uuid<T>, NuuidError> {
+        if self.variant() as u8 != T::VARIANT as u8 {
+            Err(NuuidError::WrongVariant)
+        } else if self.version() as u8 != T::VERSION as u8 {
+            Err(NuuidError::WrongVersion {
+                expected: T::VERSION,
+                found: self.version(),
+            })
+        } else {
+            Ok(self.cast())
+        }
+    }
+
+    /// Parse many hyphenated UUIDs from `s`, into `out`,
+    /// and return the parsed UUIDs.
+    ///
+    /// `s` is the UUIDs with no separator, each exactly 36 bytes,
+    /// as written by [`Uuid::format_slice`].
+    ///
+    /// # Errors
+    ///
+    /// - If the length of `s` is not a multiple of 36.
+    /// - [`ParseErrorKind::BufferTooSmall`] if `out` is too small for all
+    ///   the UUIDs in `s`.
+    /// - If any UUID is invalid, see [`Uuid::parse`].
+    ///   The error position is relative to the start of `s`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let s = "662aa7c7-7598-4d56-8bcc-a72c30f998a2\
+    ///          00000000-0000-0000-0000-000000000000";
+    /// let mut out = [Uuid::default(); 4];
+    /// let uuids = Uuid::parse_slice(s, &mut out).unwrap();
+    /// assert_eq!(uuids.len(), 2);
+    /// assert!(uuids[1].is_nil());
+    /// ```
+    pub fn parse_slice<'a>(s: &str, out: &'a mut [Uuid]) -> Result<&'a mut [Uuid], ParseUuidError> {
+        let len = ParseUuidError::invalid_length(s.len(), Some(UuidFormat::Hyphenated));
+        if s.len() % UUID_STR_LENGTH != 0 {
+            return Err(len);
+        }
+        let out = out
+            .get_mut(..s.len() / UUID_STR_LENGTH)
+            .ok_or(ParseUuidError::new(
+                ParseErrorKind::BufferTooSmall,
+                None,
+                Some(UuidFormat::Hyphenated),
+            ))?;
+        for (i, (uuid, chunk)) in out
+            .iter_mut()
+            .zip(s.as_bytes().chunks_exact(UUID_STR_LENGTH))
+            .enumerate()
+        {
+            let offset = i * UUID_STR_LENGTH;
+            *uuid = Uuid::parse_format(chunk, UuidFormat::Hyphenated).ok_or_else(|| {
+                ParseUuidError::find(chunk, UuidFormat::Hyphenated).offset(offset)
+            })?;
+        }
+        Ok(out)
+    }
+
+    /// Write many UUIDs as lowercase hyphenated strings into `buf`,
+    /// and return them as a string.
+    ///
+    /// The UUIDs are written with no separator, each exactly 36 bytes,
+    /// so they can be split with [`slice::chunks_exact`],
+    /// or parsed with [`Uuid::parse_slice`].
+    ///
+    /// # Errors
+    ///
+    /// - [`NuuidError::BufferTooSmall`] if `buf` is shorter than 36 bytes
+    ///   for each UUID.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Uuid, NAMESPACE_DNS};
+    /// let uuids = [NAMESPACE_DNS.get(), Uuid::default()];
+    /// let mut buf = [0; 100];
+    /// assert_eq!(
+    ///     Uuid::format_slice(&uuids, &mut buf).unwrap(),
+    ///     "6ba7b810-9dad-11d1-80b4-00c04fd430c8\
+    ///      00000000-0000-0000-0000-000000000000"
+    /// );
+    /// ```
+    pub fn format_slice<'a, S: State>(
+        uuids: &[Uuid<S>],
+        buf: &'a mut [u8],
+    ) -> Result<&'a mut str, NuuidError> {
+        let buf = uuids
+            .len()
+            .checked_mul(UUID_STR_LENGTH)
+            .and_then(|len| buf.get_mut(..len))
+            .ok_or(NuuidError::BufferTooSmall)?;
+        for (uuid, out) in uuids.iter().zip(buf.chunks_exact_mut(UUID_STR_LENGTH)) {
+            if let Ok(out) = out.try_into() {
+                uuid.to_str(out);
+            }
+        }
+
+        debug_assert!(
+            buf.is_ascii(),
+            "BUG: Invalid ASCII in nuuid::Uuid::format_slice"
+        );
+        // Safety: Fully initialized with ASCII
+        Ok(unsafe { from_utf8_unchecked_mut(buf) })
+    }
+
+    /// Parse a [`Uuid`] from a string that is in mixed-endian
+    ///
+    /// This method is bad and should never be needed, but there are UUIDs in
+    /// the wild that do this.
+    ///
+    /// These UUIDs are being displayed wrong, but you still need to parse them
+    /// correctly.
+    ///
+    /// See [`Uuid::from_bytes_me`] for details.
+    pub fn parse_me(s: &str) -> Result<Self, ParseUuidError> {
+        Uuid::from_str(s).map(Uuid::swap_endian)
+    }
+
+    /// Parse a [`Uuid`] from a [ULID][ulid] string
+    ///
+    /// This method is case insensitive, and following Crockford's Base32,
+    /// accepts `I` and `L` as `1`, and `O` as `0`.
+    ///
+    /// See [`Uuid::to_ulid`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::parse_ulid("01H455VB4PEX5VSKNK084SN02Q").unwrap();
+    /// assert_eq!(uuid, Uuid::parse("01890a5d-ac96-774b-bcce-b302099a8057").unwrap());
+    /// ```
+    ///
+    /// [ulid]: https://github.com/ulid/spec
+    pub fn parse_ulid(s: &str) -> Result<Self, ParseUuidError> {
+        let format = Some(UuidFormat::Ulid);
+        if s.len() != ULID_LENGTH {
+            return Err(ParseUuidError::invalid_length(s.len(), format));
+        }
+
+        let mut n: u128 = 0;
+        for (i, c) in s.char_indices() {
+            let invalid = ParseUuidError::new(ParseErrorKind::InvalidCharacter(c), Some(i), format);
+            let v = match c.to_ascii_uppercase() {
+                c @ '0'..='9' => c as u8 - b'0',
+                'O' => 0,
+                'I' | 'L' => 1,
+                c @ 'A'..='Z' => CROCKFORD
+                    .iter()
+                    .position(|&x| x == c as u8)
+                    .ok_or(invalid)? as u8,
+                _ => return Err(invalid),
+            };
+            // 26 characters is 130 bits, the highest 2 must be zero.
+            if i == 0 && v > 7 {
+                return Err(invalid);
+            }
+            n = (n << 5) | v as u128;
+        }
+        Ok(Uuid::from_bytes(n.to_be_bytes()))
+    }
+
+    /// Convert a ULID into a Version 7 UUID.
+    ///
+    /// ULIDs and Version 7 UUIDs share the same 48-bit unix millisecond
+    /// timestamp prefix, so this only needs to set the version and variant
+    /// bits, overwriting 6 bits of randomness.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Uuid, Version};
+    /// let ulid = Uuid::parse_ulid("01H455VB4PEX5VSKNK084SN02Q").unwrap();
+    /// let uuid = Uuid::from_ulid_v7(ulid);
+    /// assert_eq!(uuid.version(), Version::UnixTime);
+    /// ```
+    #[inline]
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    pub fn from_ulid_v7(ulid: Uuid) -> Uuid<RfcV7> {
+        let mut uuid = ulid;
+        uuid.set_variant(Variant::Rfc4122);
+        uuid.set_version(Version::UnixTime);
+        uuid.cast()
+    }
+
+    /// Create a new Version 4(Random) UUID.
+    ///
+    /// This requires the `getrandom` feature.
+    ///
+    /// If generating a lot of UUID's very quickly, prefer [`Uuid::new_v4_rng`].
+    ///
+    /// # Panics
+    ///
+    /// - If getrandom fails, see [`Uuid::try_new_v4`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::new_v4();
+    /// ```
+    #[cfg(feature = "getrandom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
+    #[inline]
+    pub fn new_v4() -> Uuid<RfcV4> {
+        let mut uuid = Uuid::nil();
+        OsRng.fill_bytes(&mut uuid.0);
+        uuid.set_variant(Variant::Rfc4122);
+        uuid.set_version(Version::Random);
+        uuid.cast()
+    }
+
+    /// Create a new Version 4(Random) UUID, or an error if getrandom fails.
+    ///
+    /// This requires the `getrandom` feature.
+    ///
+    /// Prefer this over [`Uuid::new_v4`] in long running programs,
+    /// where entropy may be temporarily unavailable, such as in some
+    /// sandboxes and containers.
+    ///
+    /// # Errors
+    ///
+    /// - [`NuuidError::EntropyUnavailable`] if getrandom fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NuuidError, Uuid};
+    /// let uuid = Uuid::try_new_v4()?;
+    /// # Ok::<(), NuuidError>(())
+    /// ```
+    #[cfg(feature = "getrandom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
+    #[inline]
+    pub fn try_new_v4() -> Result<Uuid<RfcV4>, NuuidError> {
+        let mut uuid = Uuid::nil();
+        OsRng
+            .try_fill_bytes(&mut uuid.0)
+            .map_err(|_| NuuidError::EntropyUnavailable)?;
+        uuid.set_variant(Variant::Rfc4122);
+        uuid.set_version(Version::Random);
+        Ok(uuid.cast())
+    }
+
+    /// A predictable sequence of Version 4 UUIDs, for tests and fixtures.
+    ///
+    /// See [`SequenceGenerator`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let mut seq = Uuid::test_sequence();
+    /// assert_eq!(seq.next().unwrap().to_string(), "00000000-0000-4000-8000-000000000001");
+    /// assert_eq!(seq.next().unwrap().to_string(), "00000000-0000-4000-8000-000000000002");
+    /// ```
+    #[inline]
+    pub const fn test_sequence() -> SequenceGenerator {
+        SequenceGenerator::new()
+    }
+
+    /// Create a new Version 4(Random) UUID, using the provided [`Rng`]
+    ///
+    /// This method is useful if you need to generate a lot of UUID's very
+    /// quickly, since it won't create and seed a new RNG each time.
+    ///
+    /// Providing a good seed is left to you, however.
+    /// If a bad seed is used, the resulting UUIDs may not be
+    /// sufficiently random or unique.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Rng, Uuid};
+    /// # let seed = [0; 32];
+    /// let mut rng = Rng::from_seed(seed);
+    /// for _ in 0..10 {
+    ///     let uuid = Uuid::new_v4_rng(&mut rng);
+    /// }
+    /// ```
+    #[inline]
+    pub fn new_v4_rng(rng: &mut Rng) -> Uuid<RfcV4> {
+        let mut uuid = Uuid::nil();
+        rng.fill_bytes(&mut uuid.0);
+        uuid.set_variant(Variant::Rfc4122);
+        uuid.set_version(Version::Random);
+        uuid.cast()
+    }
+
+    /// Create a new Version 4(Random) UUID, using any [`rand_core`] CSPRNG.
+    ///
+    /// This is how to generate random UUIDs without the `getrandom` feature,
+    /// such as in kernels and bootloaders, using whatever entropy source
+    /// the platform provides.
+    ///
+    /// [`rand_core`]: https://docs.rs/rand_core/0.6
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Uuid, Version};
+    /// # use rand::{rngs::StdRng, SeedableRng};
+    /// // Stand-in for a platform entropy source
+    /// let mut rng = StdRng::from_seed([0; 32]);
+    /// let uuid = Uuid::new_v4_from_rng(&mut rng);
+    /// assert_eq!(uuid.version(), Version::Random);
+    /// ```
+    #[inline]
+    pub fn new_v4_from_rng<R: RngCore + CryptoRng + ?Sized>(rng: &mut R) -> Uuid<RfcV4> {
+        let mut uuid = Uuid::nil();
+        rng.fill_bytes(&mut uuid.0);
+        uuid.set_variant(Variant::Rfc4122);
+        uuid.set_version(Version::Random);
+        uuid.cast()
+    }
+
+    /// Create a new Version 3 UUID with the provided name and namespace.
+    ///
+    /// # Note
+    ///
+    /// Version 3 UUID's use the obsolete MD5 algorithm,
+    /// [`Uuid::new_v5`] should be preferred.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NAMESPACE_DNS, Uuid};
+    /// let uuid = Uuid::new_v3(NAMESPACE_DNS, b"example.com");
+    /// ```
+    #[inline]
+    pub fn new_v3(namespace: Namespace, name: &[u8]) -> Uuid<RfcV3> {
+        let mut hasher = Md5::new();
+        hasher.update(namespace.get().to_bytes());
+        hasher.update(name);
+        let mut uuid = Uuid::from_bytes(hasher.finalize().into());
+        uuid.set_version(Version::Md5);
+        uuid.set_variant(Variant::Rfc4122);
+        uuid.cast()
+    }
+
+    /// Create a new Version 5 UUID with the provided name and namespace.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NAMESPACE_DNS, Uuid};
+    /// let uuid = Uuid::new_v5(NAMESPACE_DNS, b"example.com");
+    /// ```
+    #[inline]
+    pub fn new_v5(namespace: Namespace, name: &[u8]) -> Uuid<RfcV5> {
+        let mut hasher = Sha1::new();
+        hasher.update(namespace.get().to_bytes());
+        hasher.update(name);
+        let mut uuid = Uuid::from_bytes(hasher.finalize()[..16].try_into().unwrap());
+        uuid.set_version(Version::Sha1);
+        uuid.set_variant(Variant::Rfc4122);
+        uuid.cast()
+    }
+
+    /// Create a new Version 3 UUID with the provided name and namespace,
+    /// in a const context.
+    ///
+    /// This is the same as [`Uuid::new_v3`], but much slower,
+    /// so prefer that at runtime.
+    ///
+    /// See the [`uuid_v3!`] macro for UUIDs from string literals.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{state::RfcV3, NAMESPACE_DNS, Uuid};
+    /// const UUID: Uuid<RfcV3> = Uuid::new_v3_const(NAMESPACE_DNS, b"example.com");
+    /// assert_eq!(UUID, Uuid::new_v3(NAMESPACE_DNS, b"example.com"));
+    /// ```
+    #[inline]
+    pub const fn new_v3_const(namespace: Namespace, name: &[u8]) -> Uuid<RfcV3> {
+        let mut uuid = Uuid::from_bytes(hash::md5(&namespace.get().0, name));
+        uuid.set_version(Version::Md5);
+        uuid.set_variant(Variant::Rfc4122);
+        uuid.cast()
+    }
+
+    /// Create a new Version 5 UUID with the provided name and namespace,
+    /// in a const context.
+    ///
+    /// This is the same as [`Uuid::new_v5`], but much slower,
+    /// so prefer that at runtime.
+    ///
+    /// See the [`uuid_v5!`] macro for UUIDs from string literals.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{state::RfcV5, NAMESPACE_DNS, Uuid};
+    /// const UUID: Uuid<RfcV5> = Uuid::new_v5_const(NAMESPACE_DNS, b"example.com");
+    /// assert_eq!(UUID, Uuid::new_v5(NAMESPACE_DNS, b"example.com"));
+    /// ```
+    #[inline]
+    pub const fn new_v5_const(namespace: Namespace, name: &[u8]) -> Uuid<RfcV5> {
+        let hash = hash::sha1(&namespace.get().0, name);
+        let mut uuid = Uuid::nil().erase();
+        let mut i = 0;
+        while i < 16 {
+            uuid.0[i] = hash[i];
+            i += 1;
+        }
+        uuid.set_version(Version::Sha1);
+        uuid.set_variant(Variant::Rfc4122);
+        uuid.cast()
+    }
+
+    /// Create a new Version 1 UUID using the provided 60-bit timestamp,
+    /// 14-bit counter, and node.
+    ///
+    /// The 4 high bits of `timestamp` are ignored
+    ///
+    /// The 2 high bits of `counter` are ignored
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NAMESPACE_DNS, Uuid};
+    /// # let (TIMESTAMP, RANDOM, RANDOM_OR_MAC) = (0, 0, [0; 6]);
+    /// let uuid = Uuid::new_v1(TIMESTAMP, RANDOM, RANDOM_OR_MAC);
+    /// ```
+    #[inline]
+    pub fn new_v1(timestamp: u64, counter: u16, node: [u8; 6]) -> Uuid<RfcV1> {
+        let timestamp = timestamp.to_be_bytes();
+        let counter = counter.to_be_bytes();
+        Uuid::from_bytes([
+            // time_low
+            timestamp[4],
+            timestamp[5],
+            timestamp[6],
+            timestamp[7],
+            // time_mid
+            timestamp[2],
+            timestamp[3],
+            // time_hi Version, ignore highest 4 bits, skip `set_version` and set the version
+            (timestamp[0] & 0xF) | (1u8 << 4),
+            timestamp[1],
+            // clock_seq_hi Variant, skip `set_variant` and set the variant
+            (counter[0] & 0x3F) | 0x80,
+            counter[1],
+            // Node
+            node[0],
+            node[1],
+            node[2],
+            node[3],
+            node[4],
+            node[5],
+        ])
+        .cast()
+    }
+
+    /// Create a new Version 6 UUID
+    ///
+    /// This is identical to Version 1 UUIDs (see [`Uuid::new_v1`]),
+    /// except that the timestamp fields are re-ordered
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NAMESPACE_DNS, Uuid};
+    /// # let (TIMESTAMP, RANDOM, PSEUDO) = (0, 0, [0; 6]);
+    /// let uuid = Uuid::new_v6(TIMESTAMP, RANDOM, PSEUDO);
+    /// ```
+    #[inline]
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    pub fn new_v6(timestamp: u64, counter: u16, node: [u8; 6]) -> Uuid<RfcV6> {
+        // Truncate the highest 4 bits
+        // https://www.ietf.org/archive/id/draft-peabody-dispatch-new-uuid-format-04.html#section-6.1-2.14
+        let low = timestamp as u8;
+        let timestamp = (timestamp << 4).to_be_bytes();
+        let counter = counter.to_be_bytes();
+
+        Uuid::from_bytes([
+            // time_high
+            timestamp[0],
+            timestamp[1],
+            timestamp[2],
+            timestamp[3],
+            // time_mid
+            timestamp[4],
+            timestamp[5],
+            // time_low Version, shift 4 bits, skip `set_version` and set the version
+            (timestamp[6] >> 4) | (6u8 << 4),
+            low,
+            // clock_seq_hi Variant, skip `set_variant` and set the variant
+            (counter[0] & 0x3F) | 0x80,
+            counter[1],
+            // Node
+            node[0],
+            node[1],
+            node[2],
+            node[3],
+            node[4],
+            node[5],
+        ])
+        .cast()
+    }
+
+    /// Create a new Version 7 UUID
+    ///
+    /// This is similar to Version 1 and 6 UUIDs, but uses the UNIX epoch
+    /// timestamp source.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{NAMESPACE_DNS, Uuid};
+    /// # let (TIMESTAMP, RAND_A, RAND_B) = (0, 0, 0);
+    /// let uuid = Uuid::new_v7(TIMESTAMP, RAND_A, RAND_B);
+    /// ```
+    #[inline]
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    pub fn new_v7(timestamp: u64, rand_a: u16, rand_b: u64) -> Uuid<RfcV7> {
+        // Truncate the highest 16 bits
+        // https://www.ietf.org/archive/id/draft-peabody-dispatch-new-uuid-format-04.html#section-6.1-2.14
+        let timestamp = (timestamp << 16).to_be_bytes();
+        let rand_a = rand_a.to_be_bytes();
+        let rand_b = rand_b.to_be_bytes();
+
+        Uuid::from_bytes([
+            // unix_ts_ms
+            timestamp[0],
+            timestamp[1],
+            timestamp[2],
+            timestamp[3],
+            timestamp[4],
+            timestamp[5],
+            // rand_a Version, ignore highest 4 bits, skip `set_version` and set the version
+            (rand_a[0] & 0xF) | (7u8 << 4),
+            rand_a[1],
+            // rand_b, Variant, skip `set_variant` and set the variant
+            (rand_b[0] & 0x3F) | 0x80,
+            rand_b[1],
+            rand_b[2],
+            rand_b[3],
+            rand_b[4],
+            rand_b[5],
+            rand_b[6],
+            rand_b[7],
+        ])
+        .cast()
+    }
+
+    /// The smallest and largest UUIDs for Version 7 UUIDs with a timestamp
+    /// in `range`, in milliseconds since the unix epoch.
+    ///
+    /// Both bounds are inclusive, and every Version 7 UUID in `range`
+    /// sorts between them, for time bounded scans of UUID keys.
+    ///
+    /// Timestamps are clamped to the 48 bits of a Version 7 UUID.
+    /// If `range` is empty, this returns `(Uuid::MAX, Uuid::NIL)`,
+    /// which no UUID is between.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let (start, end) = Uuid::v7_range(1000..2000);
+    /// assert!(start <= Uuid::new_v7(1000, 0, 0));
+    /// assert!(Uuid::new_v7(1999, u16::MAX, u64::MAX) <= end);
+    /// assert!(Uuid::new_v7(2000, 0, 0) > end);
+    /// ```
+    #[inline]
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    pub const fn v7_range(range: core::ops::Range<u64>) -> (Uuid, Uuid) {
+        const END: u64 = 1 << 48;
+        let start = range.start;
+        let end = if range.end < END { range.end } else { END };
+        if start >= end {
+            return (Uuid::MAX, Uuid::NIL);
+        }
+        (
+            Uuid::from_u128((start as u128) << 80),
+            Uuid::from_u128((((end - 1) as u128) << 80) | ((1 << 80) - 1)),
+        )
+    }
+
+    /// Create a new Version 7 UUID at `timestamp`, with random bits from `rng`.
+    #[cfg(all(
+        feature = "experimental_uuid",
+        any(feature = "capi", feature = "wasm", feature = "critical-section")
+    ))]
+    fn new_v7_rng(timestamp: u64, rng: &mut Rng) -> Uuid<RfcV7> {
+        let mut rand = [0; 10];
+        rng.fill_bytes(&mut rand);
+        let [a0, a1, b @ ..] = rand;
+        Uuid::new_v7(
+            timestamp,
+            u16::from_be_bytes([a0, a1]),
+            u64::from_be_bytes(b),
+        )
+    }
+
+    /// Create a new Version 1 UUID at `time`.
+    ///
+    /// See [`Uuid::new_v1`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// # use std::time::SystemTime;
+    /// # let (RANDOM, RANDOM_OR_MAC) = (0, [0; 6]);
+    /// let uuid = Uuid::new_v1_at(SystemTime::now(), RANDOM, RANDOM_OR_MAC);
+    /// ```
+    #[inline]
+    #[cfg(any(test, feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn new_v1_at(time: std::time::SystemTime, counter: u16, node: [u8; 6]) -> Uuid<RfcV1> {
+        Uuid::new_v1(gregorian_from_system_time(time), counter, node)
+    }
+
+    /// Create a new Version 6 UUID at `time`.
+    ///
+    /// See [`Uuid::new_v6`] for details.
+    #[inline]
+    #[cfg(all(any(test, feature = "std"), feature = "experimental_uuid"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "experimental_uuid"))))]
+    pub fn new_v6_at(time: std::time::SystemTime, counter: u16, node: [u8; 6]) -> Uuid<RfcV6> {
+        Uuid::new_v6(gregorian_from_system_time(time), counter, node)
+    }
+
+    /// Create a new Version 7 UUID at `time`.
+    ///
+    /// See [`Uuid::new_v7`] for details.
+    #[inline]
+    #[cfg(all(any(test, feature = "std"), feature = "experimental_uuid"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "experimental_uuid"))))]
+    pub fn new_v7_at(time: std::time::SystemTime, rand_a: u16, rand_b: u64) -> Uuid<RfcV7> {
+        Uuid::new_v7(unix_ms_from_system_time(time), rand_a, rand_b)
+    }
+
+    /// Create a new Version 8 UUID
+    ///
+    /// This will set the version and variant bits as needed,
+    /// and the input will otherwise be unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::Uuid;
+    /// let uuid = Uuid::new_v8(*b"I Am 16 bytes!!!");
+    /// ```
+    #[inline]
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    pub fn new_v8(bytes: Bytes) -> Uuid<RfcV8> {
+        let mut uuid = Uuid::from_bytes(bytes);
+        uuid.set_variant(Variant::Rfc4122);
+        uuid.set_version(Version::Vendor);
+        uuid.cast()
+    }
+
+    /// Create a new Version 8 UUID with the vendor specific layout `L`.
+    ///
+    /// This is [`Uuid::new_v8`], checking the result with
+    /// [`V8Layout::is_valid`].
+    ///
+    /// See [`V8Layout`] for an example.
+    ///
+    /// # Errors
+    ///
+    /// - [`NuuidError::InvalidLayout`] if the UUID is not valid for `L`.
+    #[inline]
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    pub fn new_v8_layout<L: V8Layout>(bytes: Bytes) -> Result<Uuid<RfcV8<L>>, NuuidError> {
+        Uuid::new_v8(bytes)
+            .try_cast()
+            .ok_or(NuuidError::InvalidLayout)
+    }
+
+    /// Create a new Version 8 UUID embedding a 64-bit Snowflake ID.
+    ///
+    /// The ID is stored most significant bits first, skipping over the
+    /// version and variant bits, with the remaining bits set to zero.
+    /// This is deterministic, and UUIDs created this way sort in the same
+    /// order as their Snowflake IDs.
+    ///
+    /// The layout is as follows:
+    ///
+    /// - Bits `63..16` of `id` in `time_low` and `time_mid`
+    /// - Bits `15..4` of `id` in `time_hi_and_version`
+    /// - Bits `3..0` of `id` in the low bits of `clock_seq_hi_and_reserved`
+    ///
+    /// See [`Uuid::to_snowflake`] to get the ID back.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nuuid::{Uuid, Version};
+    /// let id = 1541815603606036480;
+    /// let uuid = Uuid::new_v8_snowflake(id);
+    /// assert_eq!(uuid.version(), Version::Vendor);
+    /// assert_eq!(uuid.to_snowflake(), id);
+    /// ```
+    #[inline]
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    pub const fn new_v8_snowflake(id: u64) -> Uuid<RfcV8> {
+        let id = id.to_be_bytes();
+        Uuid::from_bytes([
+            id[0],
+            id[1],
+            id[2],
+            id[3],
+            id[4],
+            id[5],
+            // Version, skip `set_version` and set the version
+            (8u8 << 4) | (id[6] >> 4),
+            (id[6] << 4) | (id[7] >> 4),
+            // Variant, skip `set_variant` and set the variant
+            0x80 | (id[7] & 0xF),
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ])
+        .cast()
+    }
+}
+
+/// See [`Uuid::parse`] for details.
+impl FromStr for Uuid {
+    type Err = ParseUuidError;
+
+    /// See [`Uuid::parse`] for details.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::parse(s)
+    }
+}
+
+/// Display the [`Uuid`] in uppercase hex.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::Uuid;
+/// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+/// assert_eq!(format!("{}", uuid), "662AA7C7-7598-4D56-8BCC-A72C30F998A2");
+/// ```
+impl<S: State> fmt::Display for Uuid<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:X}", self)
+    }
+}
+
+/// Display the [`Uuid`] debug representation
+///
+/// The alternate(`#`) flag can be used to get more more detailed debug
+/// information.
+///
+/// For time based and DCE states, such as `Uuid<RfcV1>`, this also includes
+/// the decoded fields, see [`Uuid::decode`].
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::Uuid;
+/// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+/// assert_eq!(format!("{:?}", uuid), "Uuid(662AA7C7-7598-4D56-8BCC-A72C30F998A2)");
+/// assert_eq!(format!("{:#?}", uuid), r#"Uuid(662AA7C7-7598-4D56-8BCC-A72C30F998A2) {
+///     Version: Random(4),
+///     Variant: Rfc4122(1),
+/// }"#);
+///
+/// let uuid = Uuid::new_v1(138788330336896890, 8648, [1, 2, 3, 4, 5, 6]);
+/// assert_eq!(format!("{:#?}", uuid), r#"Uuid(48B3477A-1340-11ED-A1C8-010203040506) {
+///     Version: Time(1),
+///     Variant: Rfc4122(1),
+///     Timestamp: 1659540233.689689000,
+///     Clock Sequence: 8648,
+///     Node: 01:02:03:04:05:06,
+/// }"#);
+/// ```
+impl<S: State> fmt::Debug for Uuid<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(
+                f,
+                r#"Uuid({:X}) {{
+    Version: {}({}),
+    Variant: {}({}),
+"#,
+                self,
+                self.version(),
+                self.version() as u8,
+                self.variant(),
+                self.variant() as u8
+            )?;
+            if S::DECODE {
+                self.decode().fmt_debug_fields(f)?;
+            }
+            write!(f, "}}")
+        } else {
+            write!(f, "Uuid({:X})", self)
+        }
+    }
+}
+
+/// Display the [`Uuid`] in lowercase
+///
+/// The alternate(`#`) flag can be used to get a URN.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::Uuid;
+/// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+/// assert_eq!(format!("{:x}", uuid), "662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+/// assert_eq!(format!("{:#x}", uuid), "urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+/// ```
+impl<S: State> fmt::LowerHex for Uuid<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", UUID_URN)?;
+        }
+        let mut buf = [0; 36];
+        let s = self.to_str(&mut buf);
+        write!(f, "{}", s)
+    }
+}
+
+/// Display the [`Uuid`] in uppercase
+///
+/// The alternate(`#`) flag can be used to get a URN.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::Uuid;
+/// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+/// assert_eq!(format!("{:X}", uuid), "662AA7C7-7598-4D56-8BCC-A72C30F998A2");
+/// assert_eq!(format!("{:#X}", uuid), "urn:uuid:662AA7C7-7598-4D56-8BCC-A72C30F998A2");
+/// ```
+impl<S: State> fmt::UpperHex for Uuid<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", UUID_URN)?;
+        }
+        let mut buf = [0; 36];
+        write!(f, "{}", self.to_str_upper(&mut buf))
+    }
+}
+
+impl<S: State> AsRef<[u8]> for Uuid<S> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<S: State> AsRef<[u8; 16]> for Uuid<S> {
+    #[inline]
+    fn as_ref(&self) -> &[u8; 16] {
+        &self.0
+    }
+}
+
+impl From<Bytes> for Uuid {
+    #[inline]
+    fn from(bytes: Bytes) -> Self {
+        Uuid::from_bytes(bytes)
+    }
+}
+
+impl<S: State> From<Uuid<S>> for Bytes {
+    #[inline]
+    fn from(uuid: Uuid<S>) -> Self {
+        uuid.to_bytes()
+    }
+}
+
+/// [`Uuid`] hashes and compares identically to its bytes,
+/// so it can be used to look up byte keyed collections.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::collections::HashSet;
+/// # use nuuid::{NAMESPACE_DNS, Uuid};
+/// let mut set = HashSet::new();
+/// set.insert(NAMESPACE_DNS.get());
+/// assert!(set.contains(&NAMESPACE_DNS.get().to_bytes()));
+/// ```
+impl<S: State> Borrow<[u8; 16]> for Uuid<S> {
+    #[inline]
+    fn borrow(&self) -> &[u8; 16] {
+        &self.0
+    }
+}
+
+macro_rules! state_conversions {
+    ($($(#[$meta:meta])* $state:ident),* $(,)?) => {
+        $(
+            /// Check that the [`Uuid`] is valid for the state.
+            ///
+            /// For the version states this checks both the version and
+            /// that the variant is [`Variant::Rfc4122`].
+            ///
+            /// # Errors
+            ///
+            /// - [`NuuidError::WrongVariant`] if the variant is wrong.
+            /// - [`NuuidError::WrongVersion`] if the version is wrong.
+            /// - [`NuuidError::NotNil`] or [`NuuidError::NotMax`] for the
+            ///   nil and max states.
+            $(#[$meta])*
+            impl TryFrom<Uuid> for Uuid<$state> {
+                type Error = NuuidError;
+
+                #[inline]
+                fn try_from(uuid: Uuid) -> Result<Self, Self::Error> {
+                    uuid.check_cast()
+                }
+            }
+
+            /// See [`Uuid::erase`] for details.
+            $(#[$meta])*
+            impl From<Uuid<$state>> for Uuid {
+                #[inline]
+                fn from(uuid: Uuid<$state>) -> Self {
+                    uuid.erase()
+                }
+            }
+        )*
+    };
+}
+
+state_conversions!(
+    RfcNil,
+    RfcV1,
+    RfcV2,
+    RfcV3,
+    RfcV4,
+    RfcV5,
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    RfcV6,
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    RfcV7,
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    RfcMax,
+);
+
+/// Check that the [`Uuid`] is a Version 8 UUID valid for the layout `L`.
+///
+/// # Errors
+///
+/// - [`NuuidError::WrongVariant`] or [`NuuidError::WrongVersion`]
+///   if it is not a Version 8 UUID.
+/// - [`NuuidError::InvalidLayout`] if it is not valid for `L`.
+#[cfg(feature = "experimental_uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+impl<L: V8Layout> TryFrom<Uuid> for Uuid<RfcV8<L>> {
+    type Error = NuuidError;
+
+    #[inline]
+    fn try_from(uuid: Uuid) -> Result<Self, Self::Error> {
+        uuid.check_cast()
+    }
+}
+
+/// See [`Uuid::erase`] for details.
+#[cfg(feature = "experimental_uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+impl<L: V8Layout> From<Uuid<RfcV8<L>>> for Uuid {
+    #[inline]
+    fn from(uuid: Uuid<RfcV8<L>>) -> Self {
+        uuid.erase()
+    }
+}
+
+/// A [`Uuid`] in one of the typed states, for runtime version dispatch.
+///
+/// See [`Uuid::classify`] for details.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{AnyUuid, Uuid};
+/// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+/// match uuid.classify() {
+///     AnyUuid::V4(uuid) => println!("Random UUID {uuid}"),
+///     AnyUuid::NonRfc(uuid) => println!("Unknown UUID {uuid}"),
+///     _ => println!("Other UUID"),
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum AnyUuid {
+    /// The special Nil UUID.
+    Nil(Uuid<RfcNil>),
+
+    /// Version 1, time based.
+    V1(Uuid<RfcV1>),
+
+    /// Version 2, DCE Security.
+    V2(Uuid<RfcV2>),
+
+    /// Version 3, MD5 name based.
+    V3(Uuid<RfcV3>),
+
+    /// Version 4, random.
+    V4(Uuid<RfcV4>),
+
+    /// Version 5, SHA-1 name based.
+    V5(Uuid<RfcV5>),
+
+    /// Version 6, re-ordered version of [`AnyUuid::V1`] for DB locality.
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    V6(Uuid<RfcV6>),
+
+    /// Version 7, unix time based.
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    V7(Uuid<RfcV7>),
+
+    /// Version 8, experimental or vendor specific format.
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    V8(Uuid<RfcV8>),
+
+    /// The special Max UUID.
+    #[cfg(feature = "experimental_uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    Max(Uuid<RfcMax>),
+
+    /// Any other UUID, such as non-RFC variants or reserved versions.
+    NonRfc(Uuid),
+}
+
+impl From<AnyUuid> for Uuid {
+    #[inline]
+    fn from(uuid: AnyUuid) -> Self {
+        match uuid {
+            AnyUuid::Nil(u) => u.erase(),
+            AnyUuid::V1(u) => u.erase(),
+            AnyUuid::V2(u) => u.erase(),
+            AnyUuid::V3(u) => u.erase(),
+            AnyUuid::V4(u) => u.erase(),
+            AnyUuid::V5(u) => u.erase(),
+            #[cfg(feature = "experimental_uuid")]
+            AnyUuid::V6(u) => u.erase(),
+            #[cfg(feature = "experimental_uuid")]
+            AnyUuid::V7(u) => u.erase(),
+            #[cfg(feature = "experimental_uuid")]
+            AnyUuid::V8(u) => u.erase(),
+            #[cfg(feature = "experimental_uuid")]
+            AnyUuid::Max(u) => u.erase(),
+            AnyUuid::NonRfc(u) => u,
+        }
+    }
+}
+
+/// A Microsoft/UEFI style GUID.
+///
+/// This type is `repr(C)` and matches the layout of the Win32 and UEFI `GUID`
+/// structures, for use in FFI.
+///
+/// The integer fields are stored in native endian, which on little-endian
+/// platforms is the mixed-endian format described in
+/// [`Uuid::from_bytes_me`].
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{Guid, NAMESPACE_DNS, Uuid};
+/// let guid = Guid::from(NAMESPACE_DNS.get());
+/// assert_eq!(guid.data1, 0x6ba7b810);
+/// assert_eq!(Uuid::from(guid), NAMESPACE_DNS.get());
+/// ```
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+#[repr(C)]
+pub struct Guid {
+    /// `time_low`
+    pub data1: u32,
+
+    /// `time_mid`
+    pub data2: u16,
+
+    /// `time_hi_and_version`
+    pub data3: u16,
+
+    /// Clock sequence and node
+    pub data4: [u8; 8],
+}
+
+impl<S: State> From<Uuid<S>> for Guid {
+    #[inline]
+    fn from(uuid: Uuid<S>) -> Self {
+        let (data1, data2, data3, data4) = uuid.to_fields();
+        Self {
+            data1,
+            data2,
+            data3,
+            data4,
+        }
+    }
+}
+
+impl From<Guid> for Uuid {
+    #[inline]
+    fn from(guid: Guid) -> Self {
+        Uuid::from_fields(guid.data1, guid.data2, guid.data3, guid.data4)
+    }
+}
+
+/// A DHCPv6 UUID based DUID, DUID-UUID, from [RFC 6355].
+///
+/// This is the 2 byte DUID type, `4`, followed by the 16 UUID bytes.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{Duid, NAMESPACE_DNS};
+/// let duid = Duid::new(NAMESPACE_DNS.get());
+/// let bytes = duid.to_bytes();
+/// assert_eq!(bytes[..2], [0, 4]);
+/// assert_eq!(Duid::from_bytes(&bytes), Ok(duid));
+/// ```
+///
+/// [RFC 6355]: https://www.rfc-editor.org/rfc/rfc6355
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Duid(Uuid);
+
+impl Duid {
+    /// The DUID type of a DUID-UUID.
+    pub const TYPE: u16 = 4;
+
+    /// Create a DUID-UUID from `uuid`.
+    #[inline]
+    pub const fn new<S: State>(uuid: Uuid<S>) -> Self {
+        Self(uuid.erase())
+    }
+
+    /// Return the [`Uuid`].
+    #[inline]
+    pub const fn get(self) -> Uuid {
+        self.0
+    }
+
+    /// Parse a DUID-UUID from `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// - [`NuuidError::InvalidDuid`] if `bytes` is not 18 bytes,
+    ///   or the DUID type is not [`Duid::TYPE`].
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, NuuidError> {
+        match bytes {
+            [t0, t1, uuid @ ..] if u16::from_be_bytes([*t0, *t1]) == Self::TYPE => {
+                Uuid::from_slice(uuid)
+                    .map(Self)
+                    .map_err(|_| NuuidError::InvalidDuid)
+            }
+            _ => Err(NuuidError::InvalidDuid),
+        }
+    }
+
+    /// Return the DUID-UUID as bytes.
+    #[inline]
+    pub const fn to_bytes(self) -> [u8; 18] {
+        let [t0, t1] = Self::TYPE.to_be_bytes();
+        let u = self.0.to_bytes();
+        [
+            t0, t1, //
+            u[0], u[1], u[2], u[3], u[4], u[5], u[6], u[7], //
+            u[8], u[9], u[10], u[11], u[12], u[13], u[14], u[15],
+        ]
+    }
+}
+
+impl<S: State> From<Uuid<S>> for Duid {
+    #[inline]
+    fn from(uuid: Uuid<S>) -> Self {
+        Duid::new(uuid)
+    }
+}
+
+impl From<Duid> for Uuid {
+    #[inline]
+    fn from(duid: Duid) -> Self {
+        duid.get()
+    }
+}
+
+impl TryFrom<&[u8]> for Duid {
+    type Error = NuuidError;
+
+    /// See [`Duid::from_bytes`] for details.
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Duid::from_bytes(bytes)
+    }
+}
+
+impl fmt::Debug for Duid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Duid({:X})", self.0)
+    }
+}
+
+/// A [`Uuid`] that is known not to be nil.
+///
+/// `Option<NonNilUuid>` is guaranteed to be the same size as [`Uuid`].
+///
+/// Unlike [`Uuid`], this type has the alignment of `u128`.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{NonNilUuid, Uuid, NAMESPACE_DNS};
+/// assert_eq!(core::mem::size_of::<Option<NonNilUuid>>(), 16);
+///
+/// let id = NonNilUuid::new(NAMESPACE_DNS.get()).unwrap();
+/// assert_eq!(id.get(), NAMESPACE_DNS.get());
+/// assert!(NonNilUuid::new(Uuid::nil()).is_none());
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct NonNilUuid(NonZeroU128);
+
+impl NonNilUuid {
+    /// Create a `NonNilUuid` if `uuid` is not nil.
+    #[inline]
+    pub const fn new<S: State>(uuid: Uuid<S>) -> Option<Self> {
+        match NonZeroU128::new(u128::from_ne_bytes(uuid.0)) {
+            Some(n) => Some(Self(n)),
+            None => None,
+        }
+    }
+
+    /// Create a `NonNilUuid` without checking whether `uuid` is nil.
+    ///
+    /// # Safety
+    ///
+    /// `uuid` must not be nil.
+    #[inline]
+    pub const unsafe fn new_unchecked<S: State>(uuid: Uuid<S>) -> Self {
+        Self(NonZeroU128::new_unchecked(u128::from_ne_bytes(uuid.0)))
+    }
+
+    /// Return the [`Uuid`].
+    #[inline]
+    pub const fn get(self) -> Uuid {
+        Uuid(self.0.get().to_ne_bytes(), PhantomData)
+    }
+}
+
+impl From<NonNilUuid> for Uuid {
+    #[inline]
+    fn from(uuid: NonNilUuid) -> Self {
+        uuid.get()
+    }
+}
+
+impl<S: State> TryFrom<Uuid<S>> for NonNilUuid {
+    type Error = NuuidError;
+
+    /// See [`NonNilUuid::new`] for details.
+    ///
+    /// # Errors
+    ///
+    /// - [`NuuidError::Nil`] if `uuid` is nil.
+    #[inline]
+    fn try_from(uuid: Uuid<S>) -> Result<Self, Self::Error> {
+        NonNilUuid::new(uuid).ok_or(NuuidError::Nil)
+    }
+}
+
+/// Compares the same as [`Uuid`].
+impl Ord for NonNilUuid {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get().cmp(&other.get())
+    }
+}
+
+impl PartialOrd for NonNilUuid {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// See [`Uuid`]s `Display` for details.
+impl fmt::Display for NonNilUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.get(), f)
+    }
+}
+
+/// See [`Uuid`]s `Debug` for details.
+impl fmt::Debug for NonNilUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.get(), f)
+    }
+}
+
+/// A [`Uuid`] ordered the same as the Cassandra and ScyllaDB `timeuuid` type.
+///
+/// See [`Uuid::cmp_timeuuid`] for details.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{TimeUuidOrd, Uuid};
+/// let mut ids = [
+///     TimeUuidOrd::new(Uuid::new_v1(0x1_0000_0000, 0, [0; 6])),
+///     TimeUuidOrd::new(Uuid::new_v1(0xFFFF_FFFF, 0, [0; 6])),
+/// ];
+/// ids.sort();
+/// assert_eq!(ids[0].get(), Uuid::new_v1(0xFFFF_FFFF, 0, [0; 6]));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct TimeUuidOrd(Uuid);
+
+impl TimeUuidOrd {
+    /// Order `uuid` as a `timeuuid`.
+    #[inline]
+    pub const fn new<S: State>(uuid: Uuid<S>) -> Self {
+        Self(uuid.erase())
+    }
+
+    /// Return the [`Uuid`].
+    #[inline]
+    pub const fn get(self) -> Uuid {
+        self.0
+    }
+}
+
+impl<S: State> From<Uuid<S>> for TimeUuidOrd {
+    #[inline]
+    fn from(uuid: Uuid<S>) -> Self {
+        TimeUuidOrd::new(uuid)
+    }
+}
+
+impl From<TimeUuidOrd> for Uuid {
+    #[inline]
+    fn from(uuid: TimeUuidOrd) -> Self {
+        uuid.get()
+    }
+}
+
+impl Ord for TimeUuidOrd {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_timeuuid(other.0)
+    }
+}
+
+impl PartialOrd for TimeUuidOrd {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// See [`Uuid`]s `Display` for details.
+impl fmt::Display for TimeUuidOrd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// See [`Uuid`]s `Debug` for details.
+impl fmt::Debug for TimeUuidOrd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+/// A [`Uuid`] used as a secret, such as a session token or API key.
+///
+/// This formats as only the first 8 hex digits, `662aa7c7-…`,
+/// or as `[REDACTED]` with the alternate(`#`) flag,
+/// so it can be safely logged.
+///
+/// Equality uses [`Uuid::ct_eq`], and the UUID must be explicitly
+/// retrieved with [`SecretUuid::expose`].
+///
+/// With the `zeroize` feature, the UUID is zeroized on drop.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{SecretUuid, Uuid};
+/// let token: SecretUuid = "662aa7c7-7598-4d56-8bcc-a72c30f998a2".parse().unwrap();
+/// assert_eq!(token.to_string(), "662aa7c7-…");
+/// assert_eq!(format!("{token:#}"), "[REDACTED]");
+/// assert_eq!(format!("{token:?}"), "SecretUuid(662aa7c7-…)");
+/// assert_eq!(
+///     token.expose(),
+///     Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap()
+/// );
+/// ```
+#[derive(Clone, Eq)]
+#[repr(transparent)]
+pub struct SecretUuid(Uuid);
+
+impl SecretUuid {
+    /// Treat `uuid` as a secret.
+    #[inline]
+    pub const fn new<S: State>(uuid: Uuid<S>) -> Self {
+        Self(uuid.erase())
+    }
+
+    /// Return the secret [`Uuid`].
+    #[inline]
+    pub const fn expose(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl<S: State> From<Uuid<S>> for SecretUuid {
+    #[inline]
+    fn from(uuid: Uuid<S>) -> Self {
+        SecretUuid::new(uuid)
+    }
+}
+
+/// See [`Uuid::parse`] for details.
+impl FromStr for SecretUuid {
+    type Err = ParseUuidError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::parse(s).map(SecretUuid::new)
+    }
+}
+
+/// Compares in constant time, see [`Uuid::ct_eq`].
+impl PartialEq for SecretUuid {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.ct_eq(other.0)
+    }
+}
+
+/// Formats as the first 8 hex digits, or `[REDACTED]` with the
+/// alternate(`#`) flag.
+impl fmt::Display for SecretUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return write!(f, "[REDACTED]");
+        }
+        let mut buf = [0; 36];
+        write!(f, "{}-…", &self.0.to_str(&mut buf)[..8])
+    }
+}
+
+/// See [`SecretUuid`]s `Display` for details.
+impl fmt::Debug for SecretUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return write!(f, "SecretUuid([REDACTED])");
+        }
+        write!(f, "SecretUuid({})", self)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl zeroize::Zeroize for SecretUuid {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl Drop for SecretUuid {
+    #[inline]
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl zeroize::ZeroizeOnDrop for SecretUuid {}
+
+/// Displays the start of a [`Uuid`], see [`Uuid::redacted`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Redacted {
+    uuid: Uuid,
+    prefix: usize,
+}
+
+impl Redacted {
+    /// Show the first `digits` hex digits, up to all 32.
+    #[inline]
+    pub const fn prefix(self, digits: usize) -> Self {
+        Self {
+            prefix: if digits < 32 { digits } else { 32 },
+            ..self
+        }
+    }
+}
+
+/// Formats as the lowercase hyphenated prefix followed by `****`,
+/// or the full UUID if the prefix is all 32 digits.
+impl fmt::Display for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0; 36];
+        let s = self.uuid.to_str(&mut buf);
+        let end = match self.prefix {
+            0 => 0,
+            n => UUID_STR_DIGITS[n - 1] as usize + 1,
+        };
+        if end == s.len() {
+            return f.write_str(s);
+        }
+        f.write_str(&s[..end])?;
+        if end > 0 && s.as_bytes()[end] == b'-' {
+            f.write_str("-")?;
+        }
+        f.write_str("****")
+    }
+}
+
+/// Formats the same as `Display`, so the UUID is never logged in full.
+impl fmt::Debug for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// A [`Uuid`] aligned to 16 bytes.
+///
+/// [`Uuid`] has an alignment of 1, so it can be used in packed structs.
+/// This is for when alignment is needed instead,
+/// such as for SIMD loads or DMA buffers.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{AlignedUuid, NAMESPACE_DNS};
+/// assert_eq!(core::mem::align_of::<AlignedUuid>(), 16);
+///
+/// let id = AlignedUuid::new(NAMESPACE_DNS.get());
+/// assert_eq!(id.get(), NAMESPACE_DNS.get());
+/// ```
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(FromBytes, IntoBytes, KnownLayout, Immutable)
+)]
+#[repr(C, align(16))]
+pub struct AlignedUuid(Uuid);
+
+impl AlignedUuid {
+    /// Align `uuid`.
+    #[inline]
+    pub const fn new<S: State>(uuid: Uuid<S>) -> Self {
+        Self(uuid.erase())
+    }
+
+    /// Return the [`Uuid`].
+    #[inline]
+    pub const fn get(self) -> Uuid {
+        self.0
+    }
+
+    /// Return a reference to the aligned UUID bytes.
+    #[inline]
+    pub const fn as_bytes(&self) -> &Bytes {
+        &self.0 .0
+    }
+
+    /// Return a mutable reference to the aligned UUID bytes.
+    #[inline]
+    pub fn as_bytes_mut(&mut self) -> &mut Bytes {
+        &mut self.0 .0
+    }
+}
+
+impl<S: State> From<Uuid<S>> for AlignedUuid {
+    #[inline]
+    fn from(uuid: Uuid<S>) -> Self {
+        AlignedUuid::new(uuid)
+    }
+}
+
+impl From<AlignedUuid> for Uuid {
+    #[inline]
+    fn from(uuid: AlignedUuid) -> Self {
+        uuid.get()
+    }
+}
+
+impl AsRef<Bytes> for AlignedUuid {
+    #[inline]
+    fn as_ref(&self) -> &Bytes {
+        self.as_bytes()
+    }
+}
+
+/// See [`Uuid`]s `Display` for details.
+impl fmt::Display for AlignedUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// See [`Uuid`]s `Debug` for details.
+impl fmt::Debug for AlignedUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+/// A generator of UUIDs that sort sequentially in SQL Server,
+/// similar to `NEWSEQUENTIALID()`.
+///
+/// Random UUIDs as `uniqueidentifier` keys cause index fragmentation,
+/// as inserts happen all over the index.
+/// UUIDs from this generator always increase under
+/// [`Uuid::cmp_sql_server`], and so are appended to the index instead.
+///
+/// The last 8 bytes are a sequence, starting from the unix millisecond
+/// timestamp, and the rest are random.
+/// This means these are not RFC UUIDs, and have no version or variant.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{Rng, SqlServerSequential};
+/// # use core::cmp::Ordering;
+/// # let seed = [0; 32];
+/// let mut gen = SqlServerSequential::new(Rng::from_seed(seed));
+/// let first = gen.next(1_660_000_000_000);
+/// let second = gen.next(1_660_000_000_000);
+/// assert_eq!(first.cmp_sql_server(second), Ordering::Less);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SqlServerSequential {
+    rng: Rng,
+    seq: u64,
+}
+
+impl SqlServerSequential {
+    /// Create a generator using `rng` for the random bytes.
+    #[inline]
+    pub fn new(rng: Rng) -> Self {
+        Self { rng, seq: 0 }
+    }
+
+    /// Generate the next UUID, at `timestamp` milliseconds since the unix epoch.
+    ///
+    /// If `timestamp` goes backwards, the sequence continues from
+    /// the previous UUID.
+    ///
+    /// Only 48 bits of `timestamp` are used, larger values are clamped to
+    /// `2^48 - 1`, in the year 10889.
+    /// The sequence stops at [`u64::MAX`], after which the sequence bytes
+    /// repeat and UUIDs no longer increase.
+    pub fn next(&mut self, timestamp: u64) -> Uuid {
+        let timestamp = timestamp.min((1 << 48) - 1);
+        self.seq = (timestamp << 16).max(self.seq.saturating_add(1));
+        let seq = self.seq.to_be_bytes();
+
+        let mut bytes = [0; 16];
+        self.rng.fill_bytes(&mut bytes[..8]);
+        // Most significant bytes first, see `Uuid::cmp_sql_server`
+        bytes[10..].copy_from_slice(&seq[..6]);
+        bytes[8..10].copy_from_slice(&seq[6..]);
+        Uuid::from_bytes(bytes)
+    }
+
+    /// Generate the next UUID, at `time`.
+    ///
+    /// See [`SqlServerSequential::next`] for details.
+    #[cfg(any(test, feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    pub fn next_at(&mut self, time: std::time::SystemTime) -> Uuid {
+        self.next(unix_ms_from_system_time(time))
+    }
+}
+
+/// A generator of predictable Version 4 UUIDs, for tests and fixtures,
+/// see [`Uuid::test_sequence`].
+///
+/// These are `00000000-0000-4000-8000-000000000001`, then `...0002`,
+/// and so on, with the correct version and variant,
+/// so they are stable and readable in snapshots.
+///
+/// The sequence is in the low 62 bits, and ends after `2^62 - 1` UUIDs.
+///
+/// These are not random, and must never be used as real identifiers.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{SequenceGenerator, Uuid, Version};
+/// let uuids: Vec<_> = SequenceGenerator::new().take(3).collect();
+/// assert_eq!(uuids[2].to_u128(), 0x4000_8000_0000_0000_0003);
+/// assert_eq!(uuids[2].version(), Version::Random);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SequenceGenerator {
+    next: u64,
+}
+
+impl SequenceGenerator {
+    const LIMIT: u64 = 1 << 62;
+
+    /// Create a generator starting from `...0001`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { next: 1 }
+    }
+}
+
+impl Default for SequenceGenerator {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for SequenceGenerator {
+    type Item = Uuid<RfcV4>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= Self::LIMIT {
+            return None;
+        }
+        let n = self.next;
+        self.next += 1;
+        // Version 4 and the RFC variant
+        Some(Uuid::from_u64_pair(0x4000, 0x8000_0000_0000_0000 | n).cast())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = Self::LIMIT - self.next;
+        (
+            usize::try_from(len).unwrap_or(usize::MAX),
+            usize::try_from(len).ok(),
+        )
+    }
+}
+
+impl core::iter::FusedIterator for SequenceGenerator {}
+
+/// A [`Hasher`] for UUID keys, which uses the UUID bits directly instead
+/// of hashing them.
+///
+/// Random UUIDs are already uniformly distributed, so this avoids the
+/// overhead of SipHash in `HashMap`s keyed by [`Uuid`], see [`UuidHashMap`].
+///
+/// The two halves of the UUID are XORed together, and mixed with two
+/// folded multiplies in [`Hasher::finish`], so that all the bits of
+/// the result depend on the varying bits of the UUID.
+/// Sequential UUIDs, such as Version 1 UUIDs from one node,
+/// still spread across all buckets.
+/// Other values are hashed with FNV-1a, which is correct, but slow.
+///
+/// This offers no protection against HashDoS, only use it where
+/// the UUIDs are not chosen by an attacker, such as those you generate.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{BuildUuidHasher, Uuid};
+/// # use std::collections::HashMap;
+/// let mut map: HashMap<Uuid, &str, BuildUuidHasher> = HashMap::default();
+/// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+/// map.insert(uuid, "value");
+/// assert_eq!(map[&uuid], "value");
+/// ```
+#[derive(Debug, Copy, Clone, Default)]
+pub struct UuidHasher(u64);
+
+impl UuidHasher {
+    const FNV_PRIME: u64 = 0x100000001b3;
+
+    /// The golden ratio, for the final multiply.
+    const MIX: u64 = 0x9E37_79B9_7F4A_7C15;
+}
+
+impl Hasher for UuidHasher {
+    /// Multiply to 128 bits and fold the halves, twice,
+    /// so both the low and high bits are mixed.
+    #[inline]
+    fn finish(&self) -> u64 {
+        let fold = |n: u64| {
+            let full = (n as u128) * (Self::MIX as u128);
+            (full as u64) ^ ((full >> 64) as u64)
+        };
+        fold(fold(self.0))
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        if let Ok(uuid) = Uuid::from_slice(bytes) {
+            let (high, low) = uuid.to_u64_pair();
+            self.0 ^= high ^ low;
+        } else {
+            for b in bytes {
+                self.0 = (self.0 ^ *b as u64).wrapping_mul(Self::FNV_PRIME);
+            }
+        }
+    }
+
+    /// Slice length prefixes, such as before the UUID bytes.
+    #[inline]
+    fn write_usize(&mut self, n: usize) {
+        self.0 = (self.0 ^ n as u64).wrapping_mul(Self::FNV_PRIME);
+    }
+}
+
+/// A [`BuildHasher`](core::hash::BuildHasher) for [`UuidHasher`].
+pub type BuildUuidHasher = BuildHasherDefault<UuidHasher>;
+
+/// A `HashMap` keyed by [`Uuid`], using [`UuidHasher`].
+///
+/// See [`UuidHasher`] for when this is appropriate.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{Uuid, UuidHashMap};
+/// let mut map = UuidHashMap::default();
+/// let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+/// map.insert(uuid, "value");
+/// assert_eq!(map[&uuid], "value");
+/// ```
+#[cfg(any(test, feature = "std"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub type UuidHashMap<V, S = Unknown> = std::collections::HashMap<Uuid<S>, V, BuildUuidHasher>;
+
+/// A namespace for name based UUIDs, see [`Uuid::new_v5`].
+///
+/// This is a distinct type from [`Uuid`] so the namespace and name
+/// arguments can't be swapped by accident.
+///
+/// Any [`Uuid`] can be used as a namespace, and the predefined namespaces
+/// are available as constants such as [`NAMESPACE_DNS`].
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{Namespace, Uuid};
+/// let app = Namespace::new(Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap());
+/// let uuid = Uuid::new_v5(app, b"user:1234");
+/// ```
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct Namespace(Uuid);
+
+impl Namespace {
+    /// Use `uuid` as a namespace.
+    #[inline]
+    pub const fn new<S: State>(uuid: Uuid<S>) -> Self {
+        Self(uuid.erase())
+    }
+
+    /// Return the namespace [`Uuid`].
+    #[inline]
+    pub const fn get(self) -> Uuid {
+        self.0
+    }
+}
+
+impl<S: State> From<Uuid<S>> for Namespace {
+    #[inline]
+    fn from(uuid: Uuid<S>) -> Self {
+        Namespace::new(uuid)
+    }
+}
+
+impl From<Namespace> for Uuid {
+    #[inline]
+    fn from(namespace: Namespace) -> Self {
+        namespace.get()
+    }
+}
+
+/// See [`Uuid`]s `Display` for details.
+impl fmt::Display for Namespace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Debug for Namespace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Namespace({:X})", self.0)
+    }
+}
+
+/// Convert from the [`uuid`](https://crates.io/crates/uuid) crate.
+///
+/// This requires the `uuid-compat` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{NAMESPACE_DNS, Uuid};
+/// # use uuid_ as uuid;
+/// let uuid = uuid::Uuid::NAMESPACE_DNS;
+/// assert_eq!(Uuid::from(uuid), NAMESPACE_DNS.get());
+/// ```
+#[cfg(feature = "uuid-compat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid-compat")))]
+impl From<uuid_::Uuid> for Uuid {
+    #[inline]
+    fn from(uuid: uuid_::Uuid) -> Self {
+        Uuid::from_bytes(uuid.into_bytes())
+    }
+}
+
+/// Convert to the [`uuid`](https://crates.io/crates/uuid) crate.
+///
+/// This requires the `uuid-compat` feature.
+#[cfg(feature = "uuid-compat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid-compat")))]
+impl<S: State> From<Uuid<S>> for uuid_::Uuid {
+    #[inline]
+    fn from(uuid: Uuid<S>) -> Self {
+        uuid_::Uuid::from_bytes(uuid.to_bytes())
+    }
+}
+
+// Safety: `Uuid` is `repr(transparent)` over `Bytes`, which is portable.
+#[cfg(feature = "rkyv")]
+unsafe impl<S: State> rkyv::Portable for Uuid<S> {}
+
+/// Archive as the 16 UUID bytes, so archived UUIDs are used directly.
+///
+/// This requires the `rkyv` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{NAMESPACE_DNS, Uuid};
+/// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&NAMESPACE_DNS.get()).unwrap();
+/// let archived = rkyv::access::<Uuid, rkyv::rancor::Error>(&bytes).unwrap();
+/// assert_eq!(archived, &NAMESPACE_DNS.get());
+/// ```
+#[cfg(feature = "rkyv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+impl<S: State> rkyv::Archive for Uuid<S> {
+    // Safety: `Uuid` is plain bytes
+    const COPY_OPTIMIZATION: rkyv::traits::CopyOptimization<Self> =
+        unsafe { rkyv::traits::CopyOptimization::enable() };
+
+    type Archived = Self;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve(&self, _: Self::Resolver, out: rkyv::Place<Self::Archived>) {
+        // Safety: `Uuid` is plain bytes, which are always initialized
+        unsafe { out.write_unchecked(*self) }
+    }
+}
+
+#[cfg(feature = "rkyv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+impl<S: State, Ser: rkyv::rancor::Fallible + ?Sized> rkyv::Serialize<Ser> for Uuid<S> {
+    #[inline]
+    fn serialize(&self, _: &mut Ser) -> Result<Self::Resolver, Ser::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rkyv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+impl<S: State, D: rkyv::rancor::Fallible + ?Sized> rkyv::Deserialize<Uuid<S>, D> for Uuid<S> {
+    #[inline]
+    fn deserialize(&self, _: &mut D) -> Result<Uuid<S>, D::Error> {
+        Ok(*self)
+    }
+}
+
+/// Typed UUIDs are checked against their state, see [`Uuid::into_state`].
+// Safety: Any bytes are a valid `Uuid`, and the state is checked.
+#[cfg(feature = "rkyv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+unsafe impl<S, C> rkyv::bytecheck::CheckBytes<C> for Uuid<S>
+where
+    S: State,
+    C: rkyv::rancor::Fallible + ?Sized,
+    C::Error: rkyv::rancor::Source,
+{
+    #[inline]
+    unsafe fn check_bytes(value: *const Self, _: &mut C) -> Result<(), C::Error> {
+        // Safety: The caller guarantees `value` is aligned and initialized
+        let uuid = unsafe { *value };
+        match uuid.erase().check_cast::<S>() {
+            Ok(_) => Ok(()),
+            Err(e) => Err(rkyv::rancor::Source::new(e)),
+        }
+    }
+}
+
+/// Serialize as the 16 UUID bytes, with no length prefix.
+///
+/// This requires the `borsh` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{NAMESPACE_DNS, Uuid};
+/// let bytes = borsh::to_vec(&NAMESPACE_DNS.get()).unwrap();
+/// assert_eq!(bytes, NAMESPACE_DNS.get().to_bytes());
+/// ```
+#[cfg(feature = "borsh")]
+#[cfg_attr(docsrs, doc(cfg(feature = "borsh")))]
+impl<S: State> borsh::BorshSerialize for Uuid<S> {
+    #[inline]
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        writer.write_all(&self.0)
+    }
+}
+
+/// Typed UUIDs are checked against their state, see [`Uuid::into_state`].
+///
+/// # Errors
+///
+/// - [`ErrorKind::InvalidData`](borsh::io::ErrorKind::InvalidData) if the
+///   UUID is not valid for the state `S`.
+#[cfg(feature = "borsh")]
+#[cfg_attr(docsrs, doc(cfg(feature = "borsh")))]
+impl<S: State> borsh::BorshDeserialize for Uuid<S> {
+    #[inline]
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        let mut bytes = [0; 16];
+        reader.read_exact(&mut bytes)?;
+        Uuid::from_bytes(bytes).try_cast().ok_or_else(|| {
+            borsh::io::Error::new(
+                borsh::io::ErrorKind::InvalidData,
+                "UUID was not the expected version",
+            )
+        })
+    }
+}
+
+/// Any 16 bytes.
+///
+/// This requires the `arbitrary` feature.
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl<'a> arbitrary::Arbitrary<'a> for Uuid {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Uuid::from_bytes(u.arbitrary()?))
+    }
+
+    #[inline]
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (16, Some(16))
+    }
+}
+
+/// Any 16 bytes, with the version and variant set for the state `S`.
+///
+/// This requires the `arbitrary` feature.
+///
+/// # Errors
+///
+/// - [`IncorrectFormat`](arbitrary::Error::IncorrectFormat) if the bytes
+///   are not valid for a vendor specific layout.
+///
+/// # Example
+///
+/// ```rust
+/// # use arbitrary::{Arbitrary, Unstructured};
+/// # use nuuid::{state::RfcV7, Uuid, Version};
+/// let mut u = Unstructured::new(&[0xAB; 16]);
+/// let uuid = Uuid::<RfcV7>::arbitrary(&mut u).unwrap();
+/// assert_eq!(uuid.version(), Version::UnixTime);
+/// ```
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl<'a, S: state::VersionedUuid> arbitrary::Arbitrary<'a> for Uuid<S> {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut uuid = Uuid::from_bytes(u.arbitrary()?);
+        uuid.set_version(S::VERSION);
+        uuid.set_variant(S::VARIANT);
+        uuid.try_cast().ok_or(arbitrary::Error::IncorrectFormat)
+    }
+
+    #[inline]
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (16, Some(16))
+    }
+}
+
+/// Random Version 4 UUIDs.
+///
+/// This requires the `rand` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{state::RfcV4, Uuid, Version};
+/// use rand::Rng;
+/// let uuid: Uuid<RfcV4> = rand::thread_rng().gen();
+/// assert_eq!(uuid.version(), Version::Random);
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl rand::distributions::Distribution<Uuid<RfcV4>> for rand::distributions::Standard {
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Uuid<RfcV4> {
+        let mut uuid = Uuid::from_bytes(rng.gen());
+        uuid.set_variant(Variant::Rfc4122);
+        uuid.set_version(Version::Random);
+        uuid.cast()
+    }
+}
+
+/// Write the 16 UUID bytes to a Postgres `uuid` column.
+///
+/// This requires the `diesel` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use diesel::prelude::*;
+/// # use nuuid::{state::RfcV4, Uuid};
+/// diesel::table! {
+///     users (id) {
+///         id -> Uuid,
+///         name -> Text,
+///     }
+/// }
+///
+/// #[derive(Queryable, Insertable)]
+/// #[diesel(table_name = users)]
+/// struct User {
+///     id: Uuid<RfcV4>,
+///     name: String,
+/// }
+/// ```
+#[cfg(feature = "diesel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+impl<S: State> diesel::serialize::ToSql<diesel::sql_types::Uuid, diesel::pg::Pg> for Uuid<S> {
+    #[inline]
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>,
+    ) -> diesel::serialize::Result {
+        use std::io::Write;
+        out.write_all(&self.0)?;
+        Ok(diesel::serialize::IsNull::No)
+    }
+}
+
+/// Typed UUIDs are checked against their state, see [`Uuid::into_state`].
+///
+/// This requires the `diesel` feature.
+///
+/// # Errors
+///
+/// - [`NuuidError::InvalidLength`] if the value is not 16 bytes.
+/// - If the UUID is not valid for the state `S`, see [`Uuid::into_state`].
+#[cfg(feature = "diesel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+impl<S: State> diesel::deserialize::FromSql<diesel::sql_types::Uuid, diesel::pg::Pg> for Uuid<S> {
+    #[inline]
+    fn from_sql(value: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {
+        Ok(Uuid::from_slice(value.as_bytes())?.check_cast()?)
+    }
+}
+
+/// Write the 16 UUID bytes to a binary column, such as a SQLite `BLOB`
+/// or MySQL `BINARY(16)`.
+///
+/// This requires the `diesel` feature.
+#[cfg(feature = "diesel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+impl<S, DB> diesel::serialize::ToSql<diesel::sql_types::Binary, DB> for Uuid<S>
+where
+    S: State,
+    DB: diesel::backend::Backend,
+    [u8]: diesel::serialize::ToSql<diesel::sql_types::Binary, DB>,
+{
+    #[inline]
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        self.0[..].to_sql(out)
+    }
+}
+
+/// Typed UUIDs are checked against their state, see [`Uuid::into_state`].
+///
+/// This requires the `diesel` feature.
+///
+/// # Errors
+///
+/// - [`NuuidError::InvalidLength`] if the value is not 16 bytes.
+/// - If the UUID is not valid for the state `S`, see [`Uuid::into_state`].
+#[cfg(feature = "diesel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+impl<S, DB> diesel::deserialize::FromSql<diesel::sql_types::Binary, DB> for Uuid<S>
+where
+    S: State,
+    DB: diesel::backend::Backend,
+    Vec<u8>: diesel::deserialize::FromSql<diesel::sql_types::Binary, DB>,
+{
+    #[inline]
+    fn from_sql(value: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+        let bytes = Vec::<u8>::from_sql(value)?;
+        Ok(Uuid::from_slice(&bytes)?.check_cast()?)
+    }
+}
+
+/// Store as a 16 byte `BLOB`.
+///
+/// This requires the `rusqlite` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{NAMESPACE_DNS, Uuid};
+/// # use rusqlite::Connection;
+/// let conn = Connection::open_in_memory().unwrap();
+/// let uuid: Uuid = conn
+///     .query_row("SELECT ?1", [NAMESPACE_DNS.get()], |row| row.get(0))
+///     .unwrap();
+/// assert_eq!(uuid, NAMESPACE_DNS.get());
+/// ```
+#[cfg(feature = "rusqlite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rusqlite")))]
+impl<S: State> rusqlite::ToSql for Uuid<S> {
+    #[inline]
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        Ok(self.0[..].into())
+    }
+}
+
+/// Reads a 16 byte `BLOB`, or any `TEXT` supported by [`Uuid::parse`].
+///
+/// Typed UUIDs are checked against their state, see [`Uuid::into_state`].
+///
+/// This requires the `rusqlite` feature.
+///
+/// # Errors
+///
+/// - [`InvalidBlobSize`](rusqlite::types::FromSqlError::InvalidBlobSize)
+///   if the `BLOB` is not 16 bytes.
+/// - [`InvalidType`](rusqlite::types::FromSqlError::InvalidType)
+///   if the value is not a `BLOB` or `TEXT`.
+/// - [`Other`](rusqlite::types::FromSqlError::Other) if the `TEXT` is not a
+///   valid UUID, or the UUID is not valid for the state `S`.
+#[cfg(feature = "rusqlite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rusqlite")))]
+impl<S: State> rusqlite::types::FromSql for Uuid<S> {
+    fn column_result(value: rusqlite::types::ValueRef<'_>) -> rusqlite::types::FromSqlResult<Self> {
+        use rusqlite::types::{FromSqlError, ValueRef};
+        let uuid = match value {
+            ValueRef::Blob(b) => {
+                Uuid::from_slice(b).map_err(|_| FromSqlError::InvalidBlobSize {
+                    expected_size: 16,
+                    blob_size: b.len(),
+                })?
+            }
+            ValueRef::Text(_) => {
+                Uuid::parse(value.as_str()?).map_err(|e| FromSqlError::Other(Box::new(e)))?
+            }
+            _ => return Err(FromSqlError::InvalidType),
+        };
+        uuid.check_cast()
+            .map_err(|e| FromSqlError::Other(Box::new(e)))
+    }
+}
+
+/// Compares all 16 bytes in constant time, see [`Uuid::ct_eq`].
+#[cfg(feature = "subtle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+impl<S: State> subtle::ConstantTimeEq for Uuid<S> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+/// Sets the UUID to nil, even for typed states such as `Uuid<RfcV4>`.
+///
+/// [`Uuid`] is [`Copy`], so this cannot be done on drop, and any copies
+/// are not zeroized.
+/// Wrap it in [`Zeroizing`](zeroize::Zeroizing) to zeroize on drop.
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl<S: State> zeroize::Zeroize for Uuid<S> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UUID_NIL: &str = "00000000-0000-0000-0000-000000000000";
+    const UUID_V4: &str = "662aa7c7-7598-4d56-8bcc-a72c30f998a2";
+    const UUID_V4_SIMPLE: &str = "662aa7c775984d568bcca72c30f998a2";
+    const UUID_V4_BRACED: &str = "{662aa7c7-7598-4d56-8bcc-a72c30f998a2}";
+    const UUID_V4_URN: &str = "urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2";
+    const UUID_V4_URN_UPPER: &str = "urn:uuid:662AA7C7-7598-4D56-8BCC-A72C30F998A2";
+    const RAW: [u8; 16] = [
+        102, 42, 167, 199, 117, 152, 77, 86, 139, 204, 167, 44, 48, 249, 152, 162,
+    ];
+
+    fn name<S: State>(fun: fn(Namespace, &[u8]) -> Uuid<S>, ver: Version) {
+        let mut rng = Rng::from_seed([7; 32]);
+        let namespace = Namespace::new(Uuid::new_v4_rng(&mut rng));
+        let namespace2 = Namespace::new(Uuid::new_v4_rng(&mut rng));
+        let uuid1 = fun(namespace, b"test");
+        let uuid2 = fun(namespace, b"test");
+        assert_eq!(
+            uuid1, uuid2,
+            "UUID's from different times with the same name/namespace must be equal"
+        );
+
+        let uuid = fun(namespace, b"Cat");
+        assert_ne!(
+            uuid, uuid2,
+            "UUID's with two different names in the same namespace must NOT be equal"
+        );
+
+        let uuid = fun(namespace2, b"test");
+        assert_ne!(
+            uuid, uuid2,
+            "UUID's with the same names in a different namespace must NOT be equal"
+        );
+
+        assert_eq!(uuid.version(), ver);
+        assert_eq!(uuid.variant(), Variant::Rfc4122);
+    }
+
+    #[test]
+    #[cfg(feature = "experimental_uuid")]
+    fn new_v6() {
+        // Values sourced from https://www.ietf.org/archive/id/draft-peabody-dispatch-new-uuid-format-04.html#name-test-vectors
+        const UUID: &str = "1EC9414C-232A-6B00-B3C8-9E6BDECED846";
+        let (ticks, counter, node) = (138648505420000000, 13256, [158, 107, 222, 206, 216, 70]);
+
+        let uuid = Uuid::new_v6(ticks, counter, node);
+        let uuid_: Uuid<RfcV6> = Uuid::parse(UUID).unwrap().into_state().unwrap();
+
+        assert_eq!(uuid.to_str_upper(&mut [0; 36]), UUID);
+        assert_eq!(uuid.version(), Version::Database);
+        assert_eq!(uuid.variant(), Variant::Rfc4122);
+
+        assert_eq!(uuid.timestamp(), uuid_.timestamp());
+        assert_eq!(uuid.timestamp().to_gregorian_100ns(), ticks);
+        assert_eq!(uuid.timestamp().version(), Version::Database);
+        assert_eq!(uuid.clock_sequence(), uuid_.clock_sequence());
+        assert_eq!(uuid.clock_sequence().unwrap().get(), counter);
+        assert_eq!(uuid.node(), uuid_.node());
+    }
+
+    #[test]
+    #[cfg(feature = "experimental_uuid")]
+    fn new_v7() {
+        // Values sourced from https://www.ietf.org/archive/id/draft-peabody-dispatch-new-uuid-format-04.html#name-uuidv7-example-test-vector
+        const UUID: &str = "017F22E2-79B0-7CC3-98C4-DC0C0C07398F";
+        let (unix_ts, rand_a, rand_b) = (0x17F22E279B0, 0xCC3, 0x18C4DC0C0C07398F);
+
+        let uuid = Uuid::new_v7(unix_ts, rand_a, rand_b);
+        let uuid_: Uuid<RfcV7> = Uuid::parse(UUID).unwrap().into_state().unwrap();
+
+        assert_eq!(uuid.to_str_upper(&mut [0; 36]), UUID);
+        assert_eq!(uuid.version(), Version::UnixTime);
+        assert_eq!(uuid.variant(), Variant::Rfc4122);
+
+        assert_eq!(uuid.timestamp(), uuid_.timestamp());
+        assert_eq!(uuid.timestamp().version(), Version::UnixTime);
+        assert_eq!(uuid.unix_ts_ms(), Some(unix_ts));
+        assert_eq!(Uuid::nil().unix_ts_ms(), None);
+        assert_eq!(uuid.timestamp().to_unix(), (1645557742, 0));
+        assert_eq!(
+            uuid.timestamp().to_gregorian_100ns(),
+            (unix_ts * 10_000) + GREGORIAN_OFFSET
+        );
+        assert_eq!(uuid.clock_sequence(), None);
+        assert_eq!(uuid.node(), None);
+    }
+
+    #[test]
+    fn time() {
+        use uuid_::{v1::*, Uuid as Uuid_};
+        let (ticks, counter, node) = (138788330336896890u64, 8648, *b"world!");
+
+        let uuid = Uuid::new_v1(ticks, counter, node);
+        let uuid_ = Uuid_::new_v1(Timestamp::from_gregorian_time(ticks, counter), &node);
+        assert_eq!(uuid.to_bytes(), *uuid_.as_bytes());
+        assert_eq!(uuid.version(), Version::Time);
+        assert_eq!(uuid.variant(), Variant::Rfc4122);
+
+        assert_eq!(
+            uuid.timestamp().to_gregorian_100ns(),
+            uuid_.get_timestamp().unwrap().to_gregorian().0
+        );
+        assert_eq!(
+            uuid.timestamp().to_unix(),
+            uuid_.get_timestamp().unwrap().to_unix()
+        );
+        assert_eq!(
+            uuid.clock_sequence().unwrap().get(),
+            uuid_.get_timestamp().unwrap().to_gregorian().1
+        );
+        assert_eq!(uuid.node().unwrap()[..], uuid_.as_fields().3[2..]);
+    }
+
+    #[test]
+    fn timestamp_from_unix() {
+        let max = Timestamp::from_unix(u64::MAX, 999_999_999);
+        assert_eq!(max.to_gregorian_100ns(), u64::MAX);
+        assert_eq!(
+            Timestamp::from_unix(u64::MAX, 0).to_gregorian_100ns(),
+            u64::MAX
+        );
+
+        // The largest representable time, and one past it
+        let secs = (u64::MAX - GREGORIAN_OFFSET) / 10_000_000;
+        let last = Timestamp::from_unix(secs, 0).to_gregorian_100ns();
+        assert_eq!(last, GREGORIAN_OFFSET + secs * 10_000_000);
+        assert_eq!(
+            Timestamp::from_unix(secs + 1, 0).to_gregorian_100ns(),
+            u64::MAX
+        );
+
+        const MAX: Timestamp = Timestamp::from_unix(u64::MAX, u32::MAX);
+        assert_eq!(MAX.to_gregorian_100ns(), u64::MAX);
+    }
+
+    #[test]
+    fn system_time() {
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let time = UNIX_EPOCH + Duration::new(1659540233, 689689000);
+        let uuid = Uuid::new_v1_at(time, 0, [0; 6]);
+        assert_eq!(uuid.timestamp().to_gregorian_100ns(), 138788330336896890);
+        assert_eq!(uuid.time_as_system_time(), Some(time));
+
+        let time = UNIX_EPOCH - Duration::from_secs(86400);
+        let uuid = Uuid::new_v1_at(time, 0, [0; 6]);
+        assert_eq!(uuid.time_as_system_time(), Some(time));
+
+        #[cfg(feature = "experimental_uuid")]
+        {
+            let time = UNIX_EPOCH + Duration::new(1659540233, 689689000);
+            let uuid = Uuid::new_v6_at(time, 0, [0; 6]);
+            assert_eq!(uuid.time_as_system_time(), Some(time));
+
+            let uuid = Uuid::new_v7_at(time, 0, 0);
+            assert_eq!(
+                uuid.time_as_system_time(),
+                Some(UNIX_EPOCH + Duration::from_millis(1659540233689))
+            );
+        }
+
+        // The Gregorian epoch, which not every platform can represent
+        let zero = Timestamp::from_gregorian_100ns(0);
+        let epoch = UNIX_EPOCH.checked_sub(Duration::from_nanos(GREGORIAN_OFFSET * 100));
+        assert_eq!(zero.to_system_time(), epoch);
+
+        // Far future times saturate
+        if let Some(far) = UNIX_EPOCH.checked_add(Duration::from_secs(u64::MAX / 4)) {
+            assert_eq!(gregorian_from_system_time(far), u64::MAX);
+        }
+    }
+
+    #[test]
+    fn dce() {
+        let uuid = Uuid::parse("000003e9-0a1e-21ef-8901-325096b39f47").unwrap();
+        assert_eq!(uuid.version(), Version::Dce);
+        assert_eq!(uuid.dce_domain(), Some(DceDomain::Group));
+        assert_eq!(uuid.dce_local_id(), Some(1001));
+        assert_eq!(uuid.decode().clock_seq.map(ClockSeq::get), Some(0x0900));
+
+        let uuid = Uuid::from_bytes(RAW);
+        assert_eq!(uuid.dce_domain(), None);
+        assert_eq!(uuid.dce_local_id(), None);
+        assert_eq!(DceDomain::from_byte(7), DceDomain::Other(7));
+        assert_eq!(DceDomain::Org.to_byte(), 2);
+    }
+
+    #[test]
+    fn md5() {
+        name(Uuid::new_v3, Version::Md5);
+        let uuid = Uuid::new_v3(NAMESPACE_DNS, b"www.widgets.com");
+        assert_eq!(
+            uuid,
+            // From Appendix B, with errata 1352, since RFC is wrong.
+            // Because of course it is.
+            Uuid::from_str("3d813cbb-47fb-32ba-91df-831e1593ac29").unwrap()
+        )
+    }
+
+    #[test]
+    fn sha1() {
+        name(Uuid::new_v5, Version::Sha1)
+    }
+
+    #[test]
+    fn parse_string() {
+        let test = &[UUID_V4, UUID_V4_URN, UUID_V4_BRACED, UUID_V4_SIMPLE];
+        for uuid in test {
+            println!("Source UUID: {}", uuid);
+            let uuid = Uuid::from_str(&uuid.to_ascii_lowercase()).unwrap();
+            println!("Parsed UUID: {}\n", uuid);
+            assert_eq!(RAW, uuid.to_bytes(), "Parsed UUID bytes don't match");
+        }
+
+        for uuid in test {
+            println!("Source UUID: {}", uuid);
+            let uuid = Uuid::from_str(&uuid.to_ascii_uppercase()).unwrap();
+            println!("Parsed UUID: {}\n", uuid);
+            assert_eq!(RAW, uuid.to_bytes(), "Parsed UUID bytes don't match");
+        }
+    }
+
+    #[test]
+    fn errors() {
+        fn source(e: &dyn core::error::Error) -> String {
+            e.to_string()
+        }
+        assert_eq!(source(&ParseUuidError::invalid()), "Invalid UUID string");
+        assert_eq!(
+            source(&NuuidError::WrongVersion {
+                expected: Version::Sha1,
+                found: Version::Random
+            }),
+            "UUID was version Random, expected version Sha1"
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        fn err(s: &str) -> (ParseErrorKind, Option<usize>, Option<UuidFormat>) {
+            let e = Uuid::parse(s).unwrap_err();
+            (e.kind(), e.position(), e.format())
+        }
+        use ParseErrorKind::*;
+        use UuidFormat::*;
+
+        assert_eq!(err("662aa7c7"), (InvalidLength(8), None, None));
+        assert_eq!(
+            err("662aa7c7-7598-4d56-8bcc-a72c30f998aZ"),
+            (InvalidCharacter('Z'), Some(35), Some(Hyphenated))
+        );
+        assert_eq!(
+            err("662aa7c7+7598-4d56-8bcc-a72c30f998a2"),
+            (ExpectedHyphen, Some(8), Some(Hyphenated))
+        );
+        assert_eq!(
+            err("662aa7c7-7598-4d56-8bcc-a72c30f998é"),
+            (InvalidCharacter('é'), Some(34), Some(Hyphenated))
+        );
+        assert_eq!(
+            err("+62aa7c775984d568bcca72c30f998a2"),
+            (InvalidCharacter('+'), Some(0), Some(Simple))
+        );
+        assert_eq!(
+            err("{662aa7c7-7598-4d56-8bcc-a72c30f998a2]"),
+            (InvalidCharacter(']'), Some(37), Some(Braced))
+        );
+        assert_eq!(
+            err("urn:uid::662aa7c7-7598-4d56-8bcc-a72c30f998a2"),
+            (InvalidCharacter('i'), Some(5), Some(Urn))
+        );
+        assert_eq!(
+            Uuid::parse("URN:UUID:662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap(),
+            Uuid::from_bytes(RAW)
+        );
+        assert_eq!(
+            Uuid::parse_const("662aa7c7-7598-4d56-8bcc-a72c30f9g8a2").unwrap_err(),
+            Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f9g8a2").unwrap_err()
+        );
+
+        let e = Uuid::parse_ulid("81H455VB4PEX5VSKNK084SN02Q").unwrap_err();
+        assert_eq!(
+            (e.kind(), e.position(), e.format()),
+            (InvalidCharacter('8'), Some(0), Some(Ulid))
+        );
+        let e = Uuid::parse_ulid("01H455VB4PEX5VSKNK084SN0UQ").unwrap_err();
+        assert_eq!(e.to_string(), "Invalid character 'U' at position 24");
+
+        let s = "662aa7c7-7598-4d56-8bcc-a72c30f998a2662aa7c7-7598-4d56-8bcc-a72c30f998x2";
+        let e = Uuid::parse_slice(s, &mut [Uuid::nil().erase(); 2]).unwrap_err();
+        assert_eq!(e.position(), Some(70));
+        assert_eq!(e.to_string(), "Invalid character 'x' at position 70");
+    }
+
+    #[test]
+    fn parse_const() {
+        const UUID: Uuid = crate::uuid!("662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+        assert_eq!(UUID, Uuid::from_bytes(RAW));
+
+        let mut rng = Rng::from_seed([1; 32]);
+        for _ in 0..64 {
+            let uuid = Uuid::new_v4_rng(&mut rng);
+            assert_eq!(Uuid::parse_const(uuid.to_str(&mut [0; 36])).unwrap(), uuid);
+            assert_eq!(
+                Uuid::parse_const(uuid.to_str_upper(&mut [0; 36])).unwrap(),
+                uuid
+            );
+        }
+
+        for (i, c) in UUID_V4.char_indices() {
+            for bad in ['g', 'G', ' ', '/', ':', '@', '`', '-'] {
+                if c == bad {
+                    continue;
+                }
+                let mut s = UUID_V4.to_owned();
+                s.replace_range(i..i + 1, &bad.to_string());
+                assert!(Uuid::parse_const(&s).is_err(), "{s}");
+            }
+        }
+        let braced = format!("{{{UUID_V4}}}");
+        for s in [
+            UUID_V4_SIMPLE,
+            UUID_V4_URN,
+            &braced,
+            &UUID_V4_URN.to_uppercase(),
+        ] {
+            assert_eq!(
+                Uuid::parse_const(s).unwrap(),
+                Uuid::parse(s).unwrap(),
+                "{s}"
+            );
+        }
+        for s in [
+            "662aa7c775984d568bcca72c30f998ag",
+            "urn:uuid;662aa7c7-7598-4d56-8bcc-a72c30f998a2",
+            "urn:uuid:662aa7c7-7598-4d56-8bcc+a72c30f998a2",
+            "{662aa7c7-7598-4d56-8bcc-a72c30f998a2)",
+            "(662aa7c7-7598-4d56-8bcc-a72c30f998a2}",
+            "{662aa7c7-7598-4d56-8bcc-a72c30f998g2}",
+        ] {
+            assert_eq!(
+                Uuid::parse_const(s).unwrap_err(),
+                Uuid::parse(s).unwrap_err(),
+                "{s}"
+            );
+        }
+        assert!(Uuid::parse_const("").is_err());
+
+        const BRACED: Uuid = crate::uuid!("{662aa7c7-7598-4d56-8bcc-a72c30f998a2}");
+        const URN: Uuid = crate::uuid!("urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+        const SIMPLE: Uuid = crate::uuid!("662aa7c775984d568bcca72c30f998a2");
+        assert_eq!([BRACED, URN, SIMPLE], [UUID; 3]);
+    }
+
+    #[test]
+    fn versioned_literals() {
+        const V4: Uuid<RfcV4> = crate::uuid_v4!("662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+        assert_eq!(V4, Uuid::from_bytes(RAW));
+        const V1: Uuid<RfcV1> = crate::uuid_v1!("{6ba7b810-9dad-11d1-80b4-00c04fd430c8}");
+        assert_eq!(V1, NAMESPACE_DNS.get());
+
+        assert_eq!(
+            Uuid::from_bytes(RAW).__const_versioned::<RfcV5>(),
+            Err(NuuidError::WrongVersion {
+                expected: Version::Sha1,
+                found: Version::Random
+            })
+        );
+        let mut ncs = Uuid::from_bytes(RAW);
+        ncs.set_variant(Variant::Ncs);
+        assert_eq!(
+            ncs.__const_versioned::<RfcV4>(),
+            Err(NuuidError::WrongVariant)
+        );
+
+        #[cfg(feature = "experimental_uuid")]
+        {
+            const V7: Uuid<RfcV7> = crate::uuid_v7!("017f22e2-79b0-7cc3-98c4-dc0c0c07398f");
+            assert_eq!(V7.version(), Version::UnixTime);
+        }
+    }
+
+    #[test]
+    fn from_bytes_versioned() {
+        const TABLE: [Uuid<RfcV4>; 1] = [match Uuid::from_bytes_v4(RAW) {
+            Ok(uuid) => uuid,
+            Err(_) => panic!("Invalid UUID"),
+        }];
+        assert_eq!(TABLE[0], Uuid::from_bytes(RAW));
+
+        assert_eq!(
+            Uuid::from_bytes_v1(RAW),
+            Err(NuuidError::WrongVersion {
+                expected: Version::Time,
+                found: Version::Random
+            })
+        );
+        assert_eq!(Uuid::from_bytes_v5([0; 16]), Err(NuuidError::WrongVariant));
+        assert!(Uuid::from_bytes_v1(NAMESPACE_DNS.get().to_bytes()).is_ok());
+    }
+
+    #[test]
+    fn name_const() {
+        let name: Vec<u8> = (0..=255).collect();
+        // Cover every padding case, across multiple blocks
+        for len in 0..=name.len() {
+            let name = &name[..len];
+            assert_eq!(
+                Uuid::new_v3_const(NAMESPACE_URL, name),
+                Uuid::new_v3(NAMESPACE_URL, name),
+                "{len}"
+            );
+            assert_eq!(
+                Uuid::new_v5_const(NAMESPACE_URL, name),
+                Uuid::new_v5(NAMESPACE_URL, name),
+                "{len}"
+            );
+        }
+
+        const V5: Uuid<RfcV5> = crate::uuid_v5!(NAMESPACE_DNS, "example.org");
+        assert_eq!(V5, Uuid::new_v5(NAMESPACE_DNS, b"example.org"));
+        const V3: Uuid<RfcV3> = crate::uuid_v3!(NAMESPACE_DNS, "example.org");
+        assert_eq!(V3, Uuid::new_v3(NAMESPACE_DNS, b"example.org"));
+    }
+
+    #[test]
+    fn slices() {
+        let mut rng = Rng::from_seed([1; 32]);
+        let uuids: Vec<Uuid> = (0..16)
+            .map(|_| Uuid::new_v4_rng(&mut rng).erase())
+            .collect();
+        let mut buf = vec![0; 16 * 36];
+        let s = Uuid::format_slice(&uuids, &mut buf).unwrap().to_owned();
+        for (uuid, chunk) in uuids.iter().zip(s.as_bytes().chunks_exact(36)) {
+            assert_eq!(uuid.to_str(&mut [0; 36]).as_bytes(), chunk);
+        }
+        assert_eq!(
+            Uuid::format_slice(&uuids, &mut buf[1..]),
+            Err(NuuidError::BufferTooSmall)
+        );
+
+        let mut out = [Uuid::nil().erase(); 20];
+        assert_eq!(Uuid::parse_slice(&s, &mut out).unwrap(), &uuids[..]);
+        let e = Uuid::parse_slice(&s, &mut out[..15]).unwrap_err();
+        assert_eq!(e.kind(), ParseErrorKind::BufferTooSmall);
+        assert_eq!(e.to_string(), "Output buffer was too small");
+        assert!(Uuid::parse_slice(&s[1..], &mut out).is_err());
+        assert!(Uuid::parse_slice(&s.replace('a', "g"), &mut out).is_err());
+        assert!(Uuid::parse_slice("", &mut out).unwrap().is_empty());
+    }
+
+    #[test]
+    fn string() {
+        let uuid = Uuid::from_bytes(RAW);
+        let mut buf = [0; 45];
+        assert_eq!(
+            uuid.to_str((&mut buf[..36]).try_into().unwrap()),
+            UUID_V4,
+            "UUID strings didn't match"
+        );
+        assert_eq!(
+            uuid.to_urn(&mut buf),
+            UUID_V4_URN,
+            "UUID URN strings didn't match"
+        );
+        assert_eq!(
+            uuid.to_urn_upper(&mut buf),
+            UUID_V4_URN_UPPER,
+            "UUID URN upper strings didn't match"
+        );
+        assert_eq!(
+            format!("{:#x}", uuid),
+            UUID_V4_URN,
+            "UUID URN Display didn't match"
+        );
+        assert_eq!(format!("{:x}", uuid), UUID_V4, "UUID Display didn't match");
+        assert_eq!(
+            format!("{}", uuid),
+            UUID_V4.to_ascii_uppercase(),
+            "UUID Display didn't match"
+        );
+        assert_eq!(
+            format!("{}", Uuid::nil()),
+            UUID_NIL,
+            "Nil UUID Display didn't work!"
+        );
+    }
+
+    #[test]
+    fn encode_hex() {
+        let mut buf = [0; 36];
+        for n in [
+            0,
+            u128::MAX,
+            u128::from_be_bytes(RAW),
+            0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210,
+        ] {
+            let uuid = Uuid::from_u128(n);
+            let hex = format!("{n:032x}");
+            let expected = format!(
+                "{}-{}-{}-{}-{}",
+                &hex[..8],
+                &hex[8..12],
+                &hex[12..16],
+                &hex[16..20],
+                &hex[20..]
+            );
+            assert_eq!(uuid.to_str(&mut buf), &*expected);
+            assert_eq!(uuid.to_str_upper(&mut buf), &*expected.to_ascii_uppercase());
+        }
+    }
+
+    #[test]
+    fn fields() {
+        let uuid = Uuid::from_bytes(RAW);
+        let (d1, d2, d3, d4) = uuid.to_fields();
+        let uuid_ = uuid_::Uuid::from_bytes(RAW);
+        assert_eq!((d1, d2, d3, &d4), uuid_.as_fields());
+        assert_eq!(Uuid::from_fields(d1, d2, d3, d4), uuid);
+
+        let (d1, d2, d3, d4) = uuid.to_fields_me();
+        assert_eq!(
+            Uuid::from_fields(d1, d2, d3, d4),
+            Uuid::from_bytes_me(uuid.to_bytes())
+        );
+        assert_eq!(Uuid::from_fields_me(d1, d2, d3, d4), uuid);
+    }
+
+    #[test]
+    fn guid() {
+        let uuid = Uuid::from_bytes(RAW);
+        let guid = Guid::from(uuid);
+        assert_eq!(core::mem::size_of::<Guid>(), 16);
+        assert_eq!(Uuid::from(guid), uuid);
+
+        // Safety: `Guid` is `repr(C)` with no padding
+        let bytes: Bytes = unsafe { core::mem::transmute(guid) };
+        if cfg!(target_endian = "little") {
+            assert_eq!(bytes, uuid.to_bytes_me());
+        } else {
+            assert_eq!(bytes, uuid.to_bytes());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "zerocopy")]
+    fn zerocopy() {
+        use zerocopy::{FromBytes, IntoBytes};
+
+        let uuid: &Uuid = Uuid::ref_from_bytes(&RAW[..]).unwrap();
+        assert_eq!(*uuid, Uuid::from_bytes(RAW));
+        assert_eq!(uuid.as_bytes(), &RAW[..]);
+    }
+
+    #[test]
+    fn bytes() {
+        let uuid = Uuid::from(RAW);
+        assert_eq!(Bytes::from(uuid), RAW);
+        assert_eq!(Uuid::from_ref(&RAW), &uuid);
+
+        let raw = [RAW, [0; 16], RAW];
+        let uuids = Uuid::from_ref_slice(&raw);
+        assert_eq!(uuids, [uuid, Uuid::nil().erase(), uuid]);
+        assert_eq!(Uuid::as_bytes_slice(uuids), raw);
+    }
+
+    #[test]
+    fn ulid() {
+        const ULID: &str = "365AKWEXCR9NB8QK575GRFK652";
+        let uuid = Uuid::from_bytes(RAW);
+        assert_eq!(uuid.to_ulid(&mut [0; 26]), ULID);
+        assert_eq!(Uuid::parse_ulid(ULID).unwrap(), uuid);
+        assert_eq!(Uuid::parse_ulid(&ULID.to_ascii_lowercase()).unwrap(), uuid);
+
+        assert_eq!(
+            Uuid::parse_ulid("0000000000000000000000000O").unwrap(),
+            Uuid::nil().erase()
+        );
+        assert_eq!(
+            Uuid::parse_ulid("7ZZZZZZZZZZZZZZZZZZZZZZZZZ").unwrap(),
+            Uuid::from_bytes([0xFF; 16])
+        );
+        // Aliases decode before the overflow check
+        for c in ["O", "o"] {
+            let s = format!("{c}0000000000000000000000000");
+            assert_eq!(Uuid::parse_ulid(&s).unwrap(), Uuid::nil().erase());
+        }
+        for c in ["I", "i", "L", "l"] {
+            let s = format!("{c}0000000000000000000000000");
+            assert_eq!(
+                Uuid::parse_ulid(&s).unwrap(),
+                Uuid::from_u128(1 << 125).erase()
+            );
+        }
+        assert!(Uuid::parse_ulid("80000000000000000000000000").is_err());
+        assert!(Uuid::parse_ulid("0000000000000000000000000U").is_err());
+        assert!(Uuid::parse_ulid("000000000000000000000000000").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "experimental_uuid")]
+    fn v1_v6() {
+        let v1 = Uuid::new_v1(0x0FED_CBA9_8765_4321, 0x3FFF, *b"world!");
+        let v6 = v1.to_v6().unwrap();
+        assert_eq!(v6.version(), Version::Database);
+        assert_eq!(v6.timestamp().to_gregorian_100ns(), 0x0FED_CBA9_8765_4321);
+        assert_eq!(v6.clock_sequence(), v1.clock_sequence());
+        assert_eq!(v6.node(), v1.node());
+        assert_eq!(v6.to_v1(), Some(v1));
+
+        assert_eq!(v1.to_v1(), None);
+        assert_eq!(v6.to_v6(), None);
+
+        let mut rng = Rng::from_seed([0; 32]);
+        let v7 = v1.to_v7_approx(&mut rng).unwrap();
+        assert_eq!(
+            v7.unix_ts_ms(),
+            v6.to_v7_approx(&mut rng).unwrap().unix_ts_ms()
+        );
+        assert_eq!(Uuid::from_bytes(RAW).to_v7_approx(&mut rng), None);
+    }
+
+    #[test]
+    #[cfg(feature = "experimental_uuid")]
+    fn snowflake() {
+        for id in [0, 1, u64::MAX, 1541815603606036480, 0x0123_4567_89AB_CDEF] {
+            let uuid = Uuid::new_v8_snowflake(id);
+            assert_eq!(uuid.version(), Version::Vendor);
+            assert_eq!(uuid.variant(), Variant::Rfc4122);
+            assert_eq!(uuid.to_snowflake(), id);
+        }
+        assert!(Uuid::new_v8_snowflake(1) < Uuid::new_v8_snowflake(2));
+        assert!(Uuid::new_v8_snowflake(0xF) < Uuid::new_v8_snowflake(0x10));
+    }
+
+    #[test]
+    fn aligned() {
+        let uuid = Uuid::from_bytes(RAW);
+        let aligned = [AlignedUuid::new(uuid); 3];
+        for id in &aligned {
+            assert_eq!(id as *const AlignedUuid as usize % 16, 0);
+            assert_eq!(id.get(), uuid);
+            assert_eq!(id.as_bytes(), &RAW);
+        }
+        assert_eq!(core::mem::size_of::<AlignedUuid>(), 16);
+        assert_eq!(aligned[0].to_string(), uuid.to_string());
+        assert_eq!(Uuid::from(aligned[0]), uuid);
+    }
+
+    #[test]
+    fn non_nil() {
+        use core::mem::size_of;
+        assert_eq!(size_of::<Option<NonNilUuid>>(), size_of::<Uuid>());
+
+        let uuid = Uuid::from_bytes(RAW);
+        let id = NonNilUuid::try_from(uuid).unwrap();
+        assert_eq!(Uuid::from(id), uuid);
+        assert_eq!(NonNilUuid::try_from(Uuid::nil()), Err(NuuidError::Nil));
+
+        let low = NonNilUuid::new(Uuid::from_bytes([
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+        ]));
+        let high = NonNilUuid::new(Uuid::from_bytes([
+            1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ]));
+        assert!(low < high);
+    }
+
+    #[test]
+    fn integer() {
+        let uuid = Uuid::from_bytes(RAW);
+        let uuid_ = uuid_::Uuid::from_bytes(RAW);
+        assert_eq!(uuid.to_u128(), uuid_.as_u128());
+        assert_eq!(uuid.to_u128_le(), uuid_.to_u128_le());
+        assert_eq!(Uuid::from_u128(uuid.to_u128()), uuid);
+        assert_eq!(Uuid::from_u128_le(uuid.to_u128_le()), uuid);
+    }
+
+    #[test]
+    fn decode() {
+        let info = Uuid::from_bytes(RAW).decode();
+        assert_eq!(info.version, Version::Random);
+        assert_eq!(info.variant, Variant::Rfc4122);
+        assert_eq!(info.timestamp, None);
+        assert_eq!(info.node, None);
+        assert_eq!(info.clock_seq, None);
+        assert_eq!(
+            info.to_string(),
+            "UUID: 662aa7c7-7598-4d56-8bcc-a72c30f998a2\nVariant: Rfc4122\nVersion: Random(4)"
+        );
+    }
+
+    #[test]
+    fn consts() {
+        assert_eq!(Uuid::NIL, Uuid::nil());
+        assert!(Uuid::NIL.is_nil());
+        assert_eq!(Uuid::MAX.to_bytes(), [0xFF; 16]);
+
+        let uuid = Uuid::from_bytes(RAW);
+        assert_eq!(
+            uuid.to_str(&mut [0; Uuid::HYPHENATED_LEN]).len(),
+            Uuid::HYPHENATED_LEN
+        );
+        assert_eq!(uuid.to_urn(&mut [0; Uuid::URN_LEN]).len(), Uuid::URN_LEN);
+        assert_eq!(UUID_V4_SIMPLE.len(), Uuid::SIMPLE_LEN);
+        assert_eq!(format!("{{{uuid}}}").len(), Uuid::BRACED_LEN);
+    }
+
+    #[test]
+    fn eq_str() {
+        let uuid = Uuid::from_bytes(RAW);
+        for s in [
+            UUID_V4,
+            UUID_V4_SIMPLE,
+            UUID_V4_URN,
+            UUID_V4_URN_UPPER,
+            &UUID_V4_SIMPLE.to_uppercase(),
+            &format!("{{{UUID_V4}}}"),
+        ] {
+            assert!(uuid.eq_str(s), "{s}");
+        }
+        for s in [
+            "",
+            "662aa7c7-7598-4d56-8bcc-a72c30f998a3",
+            "662aa7c7+7598-4d56-8bcc-a72c30f998a2",
+            "662aa7c775984d568bcca72c30f998a3",
+            "urn:uuid;662aa7c7-7598-4d56-8bcc-a72c30f998a2",
+            "(662aa7c7-7598-4d56-8bcc-a72c30f998a2}",
+            "662aa7c7-7598-4d56-8bcc-a72c30f998é",
+        ] {
+            assert!(!uuid.eq_str(s), "{s}");
+        }
+    }
+
+    #[test]
+    fn combine() {
+        let a = Uuid::from_bytes(RAW);
+        let b = Uuid::from_u128(0x5fc9fd3a_3b57_4a5a_95a5_fee2d64b5d2e);
+        assert_eq!(a.xor(b), b.xor(a));
+        assert_eq!(a.xor(b).xor(b), a);
+        assert_eq!(a.xor(a), Uuid::nil());
+        assert_eq!(a.xor(Uuid::nil()), a);
+
+        let derived = a.derive_with(b"salt");
+        assert_eq!(derived, Uuid::new_v5(Namespace::new(a), b"salt"));
+        assert_eq!(derived.version(), Version::Sha1);
+        assert_eq!(derived.variant(), Variant::Rfc4122);
+        assert_ne!(derived, a.derive_with(b"pepper"));
+        assert_ne!(derived, b.derive_with(b"salt"));
+    }
+
+    #[test]
+    fn uuid_hasher() {
+        use core::hash::{BuildHasher, Hash};
+
+        let build = BuildUuidHasher::default();
+        let a = Uuid::from_bytes(RAW);
+        assert_eq!(build.hash_one(a), build.hash_one(a));
+        assert_ne!(
+            build.hash_one(a),
+            build.hash_one(a.xor(Uuid::from_u64_pair(0, 1)))
+        );
+
+        // Same as hashing the UUID bytes directly
+        let mut hasher = UuidHasher::default();
+        a.to_bytes().hash(&mut hasher);
+        assert_eq!(build.hash_one(a), hasher.finish());
+
+        // Sequential Version 1 UUIDs from one node only differ in `time_low`,
+        // and sequences only in the last bytes, but must still spread over
+        // the low bits, used for buckets, and the high bits, used for tags.
+        let v1 = (0..4096).map(|t| Uuid::new_v1(138788330336896890 + t, 0, [1; 6]).erase());
+        let seq = Uuid::test_sequence().take(4096).map(Uuid::erase);
+        for uuids in [v1.collect::<Vec<_>>(), seq.collect()] {
+            let mut low = [false; 4096];
+            let mut high = [false; 128];
+            for uuid in uuids {
+                let hash = build.hash_one(uuid);
+                low[(hash & 0xFFF) as usize] = true;
+                high[(hash >> 57) as usize] = true;
+            }
+            // ~2590 for a uniform hash
+            assert!(low.iter().filter(|b| **b).count() > 2400);
+            assert!(high.iter().all(|b| *b));
+        }
+
+        // Other keys still work
+        assert_ne!(build.hash_one("a"), build.hash_one("b"));
+
+        let mut map = UuidHashMap::default();
+        map.insert(a, 1);
+        map.insert(Uuid::nil().erase(), 2);
+        assert_eq!(map[&a], 1);
+        assert_eq!(map[&Uuid::nil().erase()], 2);
+    }
+
+    #[test]
+    fn successor() {
+        let a = Uuid::from_bytes(RAW);
+        assert!(a < a.checked_add_one().unwrap());
+        assert!(a > a.checked_sub_one().unwrap());
+        assert_eq!(a.checked_add_one().unwrap().checked_sub_one(), Some(a));
+        assert_eq!(Uuid::NIL.checked_add_one(), Some(Uuid::from_u128(1)));
+        assert_eq!(
+            Uuid::MAX.checked_sub_one(),
+            Some(Uuid::from_u128(u128::MAX - 1))
+        );
+        assert_eq!(Uuid::NIL.checked_sub_one(), None);
+        assert_eq!(Uuid::MAX.checked_add_one(), None);
+        assert_eq!(
+            Uuid::from_u64_pair(0, u64::MAX).checked_add_one(),
+            Some(Uuid::from_u64_pair(1, 0))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "experimental_uuid")]
+    fn v7_range() {
+        let (start, end) = Uuid::v7_range(1000..2000);
+        assert_eq!(start.unix_ts_ms(), None);
+        assert_eq!(start.to_u64_pair(), (1000 << 16, 0));
+        assert_eq!(end.to_u64_pair(), ((1999 << 16) | 0xFFFF, u64::MAX));
+        for ts in [999, 1000, 1999, 2000] {
+            for (a, b) in [(0, 0), (u16::MAX, u64::MAX)] {
+                let uuid = Uuid::new_v7(ts, a, b);
+                assert_eq!(
+                    (start..=end).contains(&uuid.erase()),
+                    (1000..2000).contains(&ts)
+                );
+            }
+        }
+
+        let (start, end) = Uuid::v7_range(0..u64::MAX);
+        assert_eq!((start, end), (Uuid::NIL, Uuid::MAX));
+        assert_eq!(Uuid::v7_range(5..5), (Uuid::MAX, Uuid::NIL));
+        assert_eq!(Uuid::v7_range(0..0), (Uuid::MAX, Uuid::NIL));
+        assert_eq!(Uuid::v7_range(1 << 48..u64::MAX), (Uuid::MAX, Uuid::NIL));
+    }
+
+    #[test]
+    fn ring_position() {
+        assert_eq!(Uuid::from_bytes(RAW).ring_position(), 0xede600eb4561d5f4);
+        assert_eq!(Uuid::NIL.ring_position(), 0);
+        assert_eq!(Uuid::MAX.ring_position(), 0);
+        assert_eq!(Uuid::from_u64_pair(1, 0).ring_position(), 1);
+        assert_eq!(Uuid::from_u64_pair(0, 1).ring_position(), 1);
+    }
+
+    #[test]
+    fn redacted() {
+        let uuid = Uuid::from_bytes(RAW);
+        let r = |n| uuid.redacted().prefix(n).to_string();
+        assert_eq!(uuid.redacted().to_string(), "662aa7c7-****");
+        assert_eq!(r(0), "****");
+        assert_eq!(r(1), "6****");
+        assert_eq!(r(8), "662aa7c7-****");
+        assert_eq!(r(9), "662aa7c7-7****");
+        assert_eq!(r(12), "662aa7c7-7598-****");
+        assert_eq!(r(31), "662aa7c7-7598-4d56-8bcc-a72c30f998a****");
+        assert_eq!(r(32), UUID_V4);
+        assert_eq!(r(usize::MAX), UUID_V4);
+
+        let debug = format!("{:?}", uuid.redacted());
+        assert_eq!(debug, "662aa7c7-****");
+        assert!(!debug.contains("7598"));
+        assert_eq!(format!("{:#?}", uuid.redacted().prefix(4)), "662a****");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn collections() {
+        use collections::{UuidMap, UuidSet};
+
+        let uuids: Vec<Uuid> = (0..10u128).map(Uuid::from_u128).collect();
+        let mut set: UuidSet = uuids.iter().rev().chain(&uuids).copied().collect();
+        assert_eq!(set.as_slice(), uuids);
+        assert_eq!(set.range(uuids[2]..uuids[5]), &uuids[2..5]);
+        assert_eq!(set.range(uuids[2]..=uuids[5]), &uuids[2..=5]);
+        assert_eq!(set.range(..uuids[3]), &uuids[..3]);
+        assert!(set.range(uuids[5]..uuids[2]).is_empty());
+        assert!(!set.insert(uuids[3]));
+        assert!(set.remove(uuids[3]));
+        assert!(!set.contains(uuids[3]));
+        assert!(set.insert(uuids[3]));
+        assert_eq!(set.as_slice(), uuids);
+        set.extend([Uuid::MAX, Uuid::MAX]);
+        assert_eq!(set.len(), 11);
+
+        let mut map: UuidMap<u8> = [(uuids[1], 1), (uuids[0], 0), (uuids[1], 2)]
+            .into_iter()
+            .collect();
+        assert_eq!(map.as_slice(), [(uuids[0], 0), (uuids[1], 2)]);
+        map.extend([(uuids[0], 3), (uuids[2], 4), (uuids[0], 5)]);
+        assert_eq!(
+            map.as_slice(),
+            [(uuids[0], 5), (uuids[1], 2), (uuids[2], 4)]
+        );
+        assert_eq!(map.insert(uuids[1], 6), Some(2));
+        assert_eq!(map.get(uuids[1]), Some(&6));
+        assert_eq!(map.remove(uuids[1]), Some(6));
+        assert_eq!(map.get(uuids[1]), None);
+        assert_eq!(map.range(uuids[1]..), [(uuids[2], 4)]);
+        assert_eq!(map.keys().collect::<Vec<_>>(), [uuids[0], uuids[2]]);
+    }
+
+    #[test]
+    fn test_sequence() {
+        let mut seq = Uuid::test_sequence();
+        let first = seq.next().unwrap();
+        assert_eq!(
+            first,
+            Uuid::parse("00000000-0000-4000-8000-000000000001").unwrap()
+        );
+        assert_eq!(first.classify(), AnyUuid::V4(first));
+        assert_eq!(
+            seq.nth(8).unwrap().to_u64_pair(),
+            (0x4000, 0x8000_0000_0000_000A)
+        );
+
+        let mut seq = SequenceGenerator {
+            next: SequenceGenerator::LIMIT - 1,
+        };
+        let last = seq.next().unwrap();
+        assert_eq!(last.to_u64_pair(), (0x4000, 0xBFFF_FFFF_FFFF_FFFF));
+        assert_eq!(last.variant(), Variant::Rfc4122);
+        assert_eq!(seq.next(), None);
+        assert_eq!(seq.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn cmp_by_time() {
+        let v1 = Uuid::new_v1(138788330336896890, 0, [0xFF; 6]);
+        let later = Uuid::new_v1(138788330336896891, 0, [0; 6]);
+        assert_eq!(v1.cmp_by_time(later), Some(Ordering::Less));
+        assert_eq!(later.cmp_by_time(v1), Some(Ordering::Greater));
+        assert_eq!(
+            v1.cmp_by_time(Uuid::new_v1(138788330336896890, 1, [0; 6])),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(v1.cmp_by_time(Uuid::from_bytes(RAW)), None);
+        assert_eq!(Uuid::from_bytes(RAW).cmp_by_time(v1), None);
+
+        #[cfg(feature = "experimental_uuid")]
+        {
+            // 1659540233689 ms
+            let v6 = Uuid::new_v6(138788330336896890, 0, [0; 6]);
+            let v7 = Uuid::new_v7(1659540233689, 0, 0);
+            assert_eq!(v6.cmp_by_time(v1), Some(Ordering::Equal));
+            assert_eq!(v7.cmp_by_time(v1), Some(Ordering::Less));
+            assert_eq!(
+                Uuid::new_v7(1659540233690, 0, 0).cmp_by_time(v6),
+                Some(Ordering::Greater)
+            );
+        }
+    }
+
+    #[test]
+    fn rng_rounds() {
+        let seed = [3; 32];
+        let fill = |mut rng: Rng| {
+            let mut buf = [0; 16];
+            rng.fill_bytes(&mut buf);
+            buf
+        };
+        let mut expected = [0; 16];
+        ChaCha20Rng::from_seed(seed).fill_bytes(&mut expected);
+        assert_eq!(fill(Rng::from_seed(seed)), expected);
+        assert_eq!(Rng::from_seed(seed).rounds(), ChaChaRounds::default());
+
+        ChaCha8Rng::from_seed(seed).fill_bytes(&mut expected);
+        assert_eq!(
+            fill(Rng::from_seed_rounds(seed, ChaChaRounds::ChaCha8)),
+            expected
+        );
+        ChaCha12Rng::from_seed(seed).fill_bytes(&mut expected);
+        assert_eq!(
+            fill(Rng::from_seed_rounds(seed, ChaChaRounds::ChaCha12)),
+            expected
+        );
+
+        for rounds in [
+            ChaChaRounds::ChaCha8,
+            ChaChaRounds::ChaCha12,
+            ChaChaRounds::ChaCha20,
+        ] {
+            assert_eq!(Rng::from_seed_rounds(seed, rounds).rounds(), rounds);
+        }
+        #[cfg(feature = "getrandom")]
+        {
+            assert_eq!(Rng::new_chacha8().rounds(), ChaChaRounds::ChaCha8);
+            assert_eq!(Rng::new_chacha12().rounds(), ChaChaRounds::ChaCha12);
+            assert_eq!(Rng::new_chacha20().rounds(), ChaChaRounds::ChaCha20);
+            assert_eq!(Rng::new().rounds(), ChaChaRounds::ChaCha20);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "getrandom")]
+    fn reseed_policy() {
+        let seed = [3; 32];
+        let outputs = |mut rng: Rng| {
+            let mut out = [[0; 16]; 4];
+            for buf in &mut out {
+                rng.fill_bytes(buf);
+            }
+            out
+        };
+        let plain = outputs(Rng::from_seed(seed));
+
+        let never = Rng::from_seed(seed).with_reseed_policy(ReseedPolicy::Never);
+        assert_eq!(outputs(never), plain);
+
+        let two = outputs(Rng::from_seed(seed).with_reseed_policy(ReseedPolicy::Outputs(2)));
+        assert_eq!(two[..2], plain[..2]);
+        assert_ne!(two[2], plain[2]);
+
+        let mut rng = Rng::from_seed_rounds(seed, ChaChaRounds::ChaCha8)
+            .with_reseed_policy(ReseedPolicy::Interval(core::time::Duration::ZERO));
+        let mut first = [0; 16];
+        rng.fill_bytes(&mut first);
+        assert_ne!(
+            first,
+            outputs(Rng::from_seed_rounds(seed, ChaChaRounds::ChaCha8))[0]
+        );
+        assert_eq!(rng.rounds(), ChaChaRounds::ChaCha8);
+
+        let hour = ReseedPolicy::Interval(core::time::Duration::from_secs(3600));
+        let rng = Rng::from_seed(seed).with_reseed_policy(hour);
+        assert_eq!(rng.reseed_policy(), hour);
+        assert_eq!(outputs(rng), plain);
+
+        let mut rng = Rng::from_seed(seed);
+        rng.reseed().unwrap();
+        assert_ne!(outputs(rng), plain);
+    }
+
+    #[test]
+    fn predicates() {
+        assert!(Uuid::MAX.is_max());
+        assert!(!Uuid::NIL.is_max());
+        assert!(!Uuid::from_bytes(RAW).is_max());
+
+        assert!(Uuid::from_bytes(RAW).is_rfc());
+        assert!(!Uuid::NIL.is_rfc());
+        assert!(!Uuid::MAX.is_rfc());
+
+        assert!(NAMESPACE_DNS.get().is_time_based());
+        assert!(!Uuid::from_bytes(RAW).is_time_based());
+        assert!(!Uuid::NIL.is_time_based());
+        #[cfg(feature = "experimental_uuid")]
+        {
+            assert!(Uuid::new_v7(0, 0, 0).is_time_based());
+            assert!(Uuid::new_v6(0, 0, *b"world!").is_time_based());
+        }
+    }
+
+    #[test]
+    fn bluetooth() {
+        assert_eq!(
+            BLUETOOTH_BASE_UUID,
+            Uuid::parse("00000000-0000-1000-8000-00805f9b34fb").unwrap()
+        );
+        assert_eq!(BLUETOOTH_BASE_UUID.to_bt_short(), Some(0));
+        assert_eq!(
+            Uuid::from_bt_short(0x1234_5678).to_bt_short(),
+            Some(0x1234_5678)
+        );
+        assert_eq!(Uuid::from_bytes(RAW).to_bt_short(), None);
+    }
+
+    #[test]
+    fn literal() {
+        let uuid = Uuid::from_bytes(RAW);
+        assert_eq!(
+            uuid.as_rust_literal(&mut [0; Uuid::RUST_LITERAL_LEN]),
+            r#"uuid!("662aa7c7-7598-4d56-8bcc-a72c30f998a2")"#
+        );
+        const LITERAL: Uuid = crate::uuid!("662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+        assert_eq!(LITERAL, uuid);
+        assert_eq!(
+            uuid.as_c_array(&mut [0; Uuid::C_ARRAY_LEN]),
+            "{0x66,0x2a,0xa7,0xc7,0x75,0x98,0x4d,0x56,0x8b,0xcc,0xa7,0x2c,0x30,0xf9,0x98,0xa2}"
+        );
+    }
+
+    #[test]
+    fn endian() {
+        let uuid_be = Uuid::from_bytes(RAW);
+        assert_eq!(uuid_be.version(), Version::Random);
+        assert_eq!(uuid_be.variant(), Variant::Rfc4122);
+
+        let uuid_le = Uuid::from_bytes_me(uuid_be.to_bytes_me());
+        assert_eq!(uuid_le.version(), Version::Random);
+        assert_eq!(uuid_le.variant(), Variant::Rfc4122);
+
+        assert_eq!(uuid_le, uuid_be);
+        assert_ne!(uuid_be.to_bytes_me(), uuid_be.to_bytes());
+
+        // Terrible UUID sourced from my partition table on Linux.
+        // Either Linux displays them wrong, or parted stores them wrong.
+        // Either way, its swapped.
+        const UUID: &str = "20169084-b186-884f-b110-3db2c37eb8b5";
+        let uuid = Uuid::parse_me(UUID).unwrap();
+        let bad_uuid = Uuid::parse(UUID).unwrap();
+
+        assert_ne!(bad_uuid.version(), Version::Random);
+        assert_eq!(uuid.version(), Version::Random);
+        assert_eq!(uuid.variant(), Variant::Rfc4122);
+        // Cant be equal because endian
+        assert_ne!(uuid.to_str(&mut [0; 36]), UUID);
+    }
+
+    #[test]
+    fn info() {
+        let uuid = Uuid::from_bytes(RAW);
+        assert_eq!(uuid.version(), Version::Random);
+        assert_eq!(uuid.variant(), Variant::Rfc4122);
+        #[cfg(feature = "getrandom")]
+        {
+            let uuid = Uuid::new_v4();
+            assert_eq!(uuid.version(), Version::Random);
+            assert_eq!(uuid.variant(), Variant::Rfc4122);
+
+            let uuid = Uuid::try_new_v4().unwrap();
+            assert_eq!(uuid.version(), Version::Random);
+            assert_eq!(uuid.variant(), Variant::Rfc4122);
+            assert!(Rng::try_new().is_ok());
+        }
+    }
+
+    #[test]
+    fn try_from_state() {
+        let uuid = Uuid::from_bytes(RAW);
+        assert!(Uuid::<RfcV4>::try_from(uuid).is_ok());
+        assert_eq!(
+            Uuid::<RfcV5>::try_from(uuid),
+            Err(NuuidError::WrongVersion {
+                expected: Version::Sha1,
+                found: Version::Random
+            })
+        );
+        assert!(Uuid::<RfcNil>::try_from(Uuid::from_bytes([0; 16])).is_ok());
+        assert_eq!(Uuid::<RfcNil>::try_from(uuid), Err(NuuidError::NotNil));
+
+        let mut bytes = RAW;
+        bytes[8] = 0x0B;
+        assert_eq!(
+            Uuid::<RfcV4>::try_from(Uuid::from_bytes(bytes)),
+            Err(NuuidError::WrongVariant)
+        );
+    }
+
+    #[test]
+    fn into_state() {
+        let uuid = Uuid::from_bytes(RAW);
+        assert_eq!(uuid.into_state::<RfcV3>(), Err(uuid));
+        assert_eq!(uuid.as_state::<RfcV3>(), None);
+
+        let typed = uuid.into_state::<RfcV4>().unwrap();
+        assert_eq!(uuid.as_state::<RfcV4>(), Some(&typed));
+        assert_eq!(typed.into_state::<Unknown>(), Ok(uuid));
+        assert_eq!(typed.erase(), uuid);
+        assert_eq!(Uuid::<Unknown>::from(typed), uuid);
+    }
+
+    #[test]
+    fn classify() {
+        assert!(matches!(Uuid::nil().classify(), AnyUuid::Nil(_)));
+        assert!(matches!(Uuid::from_bytes(RAW).classify(), AnyUuid::V4(_)));
+        assert!(matches!(
+            Uuid::new_v1(0, 0, [0; 6]).classify(),
+            AnyUuid::V1(_)
+        ));
+
+        let mut bytes = RAW;
+        bytes[8] = 0x0B;
+        let uuid = Uuid::from_bytes(bytes);
+        assert_eq!(uuid.classify(), AnyUuid::NonRfc(uuid));
+        assert_eq!(Uuid::from(uuid.classify()), uuid);
+        #[cfg(feature = "experimental_uuid")]
+        assert!(matches!(Uuid::max().classify(), AnyUuid::Max(_)));
+    }
+
+    #[test]
+    #[cfg(feature = "experimental_uuid")]
+    fn v8_layout() {
+        enum Even {}
+
+        impl V8Layout for Even {
+            fn is_valid(uuid: Uuid) -> bool {
+                uuid.to_bytes()[15] % 2 == 0
+            }
+        }
+
+        let even = Uuid::new_v8([0; 16]).erase();
+        let odd = Uuid::new_v8([1; 16]).erase();
+        assert!(Uuid::<RfcV8<Even>>::try_from(even).is_ok());
+        assert_eq!(
+            Uuid::<RfcV8<Even>>::try_from(odd),
+            Err(NuuidError::InvalidLayout)
+        );
+        assert_eq!(
+            Uuid::<RfcV8<Even>>::try_from(Uuid::from_bytes(RAW)),
+            Err(NuuidError::WrongVersion {
+                expected: Version::Vendor,
+                found: Version::Random
+            })
+        );
+        assert!(Uuid::<RfcV8>::try_from(odd).is_ok());
+        assert_eq!(
+            Uuid::new_v8_layout::<Even>([1; 16]),
+            Err(NuuidError::InvalidLayout)
+        );
     }
-}
 
-impl AsRef<[u8]> for Uuid {
-    #[inline]
-    fn as_ref(&self) -> &[u8] {
-        &self.0
-    }
-}
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        use serde_test::{assert_de_tokens, assert_tokens, Configure, Readable, Token};
 
-impl AsRef<[u8; 16]> for Uuid {
-    #[inline]
-    fn as_ref(&self) -> &[u8; 16] {
-        &self.0
-    }
-}
+        let uuid = Uuid::from_bytes(RAW);
+        assert_tokens(&uuid.readable(), &[Token::Str(UUID_V4)]);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let mut tokens = vec![Token::Tuple { len: 16 }];
+        tokens.extend(RAW.iter().map(|b| Token::U8(*b)));
+        tokens.push(Token::TupleEnd);
+        assert_tokens(&uuid.compact(), &tokens);
 
-    const UUID_NIL: &str = "00000000-0000-0000-0000-000000000000";
-    const UUID_V4: &str = "662aa7c7-7598-4d56-8bcc-a72c30f998a2";
-    const UUID_V4_SIMPLE: &str = "662aa7c775984d568bcca72c30f998a2";
-    const UUID_V4_BRACED: &str = "{662aa7c7-7598-4d56-8bcc-a72c30f998a2}";
-    const UUID_V4_URN: &str = "urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2";
-    const UUID_V4_URN_UPPER: &str = "urn:uuid:662AA7C7-7598-4D56-8BCC-A72C30F998A2";
-    const RAW: [u8; 16] = [
-        102, 42, 167, 199, 117, 152, 77, 86, 139, 204, 167, 44, 48, 249, 152, 162,
-    ];
+        assert_de_tokens(&uuid.readable(), &[Token::Str(UUID_V4_URN_UPPER)]);
+        assert_de_tokens(&uuid.readable(), &[Token::Bytes(&RAW)]);
+        assert_de_tokens(&uuid.compact(), &[Token::Bytes(&RAW)]);
 
-    fn name(fun: fn(Uuid, &[u8]) -> Uuid, ver: Version) {
-        let namespace = Uuid::new_v4();
-        let namespace2 = Uuid::new_v4();
-        let uuid1 = fun(namespace, b"test");
-        let uuid2 = fun(namespace, b"test");
-        assert_eq!(
-            uuid1, uuid2,
-            "UUID's from different times with the same name/namespace must be equal"
+        let typed: Uuid<RfcV4> = uuid.into_state().unwrap();
+        assert_tokens(&typed.readable(), &[Token::Str(UUID_V4)]);
+        serde_test::assert_de_tokens_error::<Readable<Uuid<RfcV5>>>(
+            &[Token::Str(UUID_V4)],
+            "UUID was version Random, expected version Sha1",
         );
 
-        let uuid = fun(namespace, b"Cat");
-        assert_ne!(
-            uuid, uuid2,
-            "UUID's with two different names in the same namespace must NOT be equal"
+        #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+        struct Parent(#[serde(with = "crate::serde::nil_as_none")] Option<Uuid<RfcV4>>);
+
+        assert_tokens(
+            &Parent(Some(typed)).readable(),
+            &[Token::NewtypeStruct { name: "Parent" }, Token::Str(UUID_V4)],
+        );
+        assert_tokens(
+            &Parent(None).readable(),
+            &[
+                Token::NewtypeStruct { name: "Parent" },
+                Token::Str(UUID_NIL),
+            ],
         );
+    }
 
-        let uuid = fun(namespace2, b"test");
-        assert_ne!(
-            uuid, uuid2,
-            "UUID's with the same names in a different namespace must NOT be equal"
+    #[test]
+    #[cfg(feature = "rkyv")]
+    fn rkyv() {
+        use rkyv::rancor::Error;
+
+        let uuid = Uuid::from_bytes(RAW);
+        let bytes = rkyv::to_bytes::<Error>(&uuid).unwrap();
+        assert_eq!(&bytes[..], &RAW);
+        assert_eq!(rkyv::access::<Uuid, Error>(&bytes).unwrap(), &uuid);
+        assert_eq!(
+            rkyv::from_bytes::<Uuid<RfcV4>, Error>(&bytes).unwrap(),
+            uuid
         );
+        assert!(rkyv::access::<Uuid<RfcV5>, Error>(&bytes).is_err());
+    }
 
-        assert_eq!(uuid.version(), ver);
-        assert_eq!(uuid.variant(), Variant::Rfc4122);
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn borsh() {
+        let uuid = Uuid::from_bytes(RAW);
+        let bytes = borsh::to_vec(&uuid).unwrap();
+        assert_eq!(bytes, RAW);
+        assert_eq!(borsh::from_slice::<Uuid>(&bytes).unwrap(), uuid);
+        assert_eq!(borsh::from_slice::<Uuid<RfcV4>>(&bytes).unwrap(), uuid);
+        assert!(borsh::from_slice::<Uuid<RfcV5>>(&bytes).is_err());
+        assert!(borsh::from_slice::<Uuid>(&bytes[1..]).is_err());
     }
 
     #[test]
-    #[cfg(feature = "experimental_uuid")]
-    fn new_v6() {
-        // Values sourced from https://www.ietf.org/archive/id/draft-peabody-dispatch-new-uuid-format-04.html#name-test-vectors
-        const UUID: &str = "1EC9414C-232A-6B00-B3C8-9E6BDECED846";
-        let (ticks, counter, node) = (138648505420000000, 13256, [158, 107, 222, 206, 216, 70]);
+    #[cfg(feature = "arbitrary")]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
 
-        let uuid = Uuid::new_v6(ticks, counter, node);
-        let uuid_ = Uuid::parse(UUID).unwrap();
+        let mut u = Unstructured::new(&RAW);
+        assert_eq!(
+            Uuid::<Unknown>::arbitrary(&mut u).unwrap(),
+            Uuid::from_bytes(RAW)
+        );
 
-        assert_eq!(uuid.to_str_upper(&mut [0; 36]), UUID);
-        assert_eq!(uuid.version(), Version::Database);
+        let mut u = Unstructured::new(&[0xFF; 16]);
+        let uuid = Uuid::<RfcV3>::arbitrary(&mut u).unwrap();
+        assert_eq!(uuid.version(), Version::Md5);
         assert_eq!(uuid.variant(), Variant::Rfc4122);
-
-        assert_eq!(uuid.timestamp(), uuid_.timestamp());
-        assert_eq!(uuid.clock_sequence(), uuid_.clock_sequence());
-        assert_eq!(uuid.node()[..], uuid_.node());
     }
 
     #[test]
-    #[cfg(feature = "experimental_uuid")]
-    fn new_v7() {
-        // Values sourced from https://www.ietf.org/archive/id/draft-peabody-dispatch-new-uuid-format-04.html#name-uuidv7-example-test-vector
-        const UUID: &str = "017F22E2-79B0-7CC3-98C4-DC0C0C07398F";
-        let (unix_ts, rand_a, rand_b) = (0x17F22E279B0, 0xCC3, 0x18C4DC0C0C07398F);
-
-        let uuid = Uuid::new_v7(unix_ts, rand_a, rand_b);
-        let uuid_ = Uuid::parse(UUID).unwrap();
+    #[cfg(feature = "prost")]
+    fn prost() {
+        use crate::prost::UuidMessage;
+        use ::prost::Message;
 
-        assert_eq!(uuid.to_str_upper(&mut [0; 36]), UUID);
-        assert_eq!(uuid.version(), Version::UnixTime);
-        assert_eq!(uuid.variant(), Variant::Rfc4122);
+        let uuid = Uuid::from_bytes(RAW);
+        let msg = UuidMessage::from(uuid);
+        let decoded = UuidMessage::decode(&msg.encode_to_vec()[..]).unwrap();
+        assert_eq!(Uuid::from(decoded), uuid);
 
-        assert_eq!(uuid.timestamp(), uuid_.timestamp());
-        assert_eq!(uuid.clock_sequence(), uuid_.clock_sequence());
-        assert_eq!(uuid.node()[..], uuid_.node());
+        let bytes: ::prost::bytes::Bytes = uuid.into();
+        assert_eq!(Uuid::try_from(bytes), Ok(uuid));
+        assert_eq!(Uuid::try_from(vec![0; 15]), Err(NuuidError::InvalidLength));
     }
 
     #[test]
-    fn time() {
-        use uuid_::{v1::*, Uuid as Uuid_};
-        let (ticks, counter, node) = (138788330336896890u64, 8648, *b"world!");
+    #[cfg(feature = "diesel")]
+    fn diesel() {
+        use diesel::{debug_query, pg::Pg, prelude::*};
 
-        let uuid = Uuid::new_v1(ticks, counter, node);
-        let uuid_ = Uuid_::new_v1(Timestamp::from_rfc4122(ticks, counter), &node);
-        assert_eq!(uuid.to_bytes(), *uuid_.as_bytes());
-        assert_eq!(uuid.version(), Version::Time);
-        assert_eq!(uuid.variant(), Variant::Rfc4122);
+        diesel::table! {
+            users (id) {
+                id -> Uuid,
+                key -> Binary,
+            }
+        }
 
+        let uuid = Uuid::from_bytes(RAW);
+        let query = users::table.filter(users::id.eq(uuid).and(users::key.eq(&uuid)));
         assert_eq!(
-            uuid.timestamp(),
-            uuid_.get_timestamp().unwrap().to_rfc4122().0
-        );
-        assert_eq!(
-            uuid.clock_sequence(),
-            uuid_.get_timestamp().unwrap().to_rfc4122().1
+            debug_query::<Pg, _>(&query).to_string(),
+            format!(
+                "SELECT \"users\".\"id\", \"users\".\"key\" FROM \"users\" \
+                WHERE ((\"users\".\"id\" = $1) AND (\"users\".\"key\" = $2)) \
+                -- binds: [{uuid:?}, {uuid:?}]"
+            )
         );
-        assert_eq!(uuid.node()[..], uuid_.as_fields().3[2..]);
     }
 
     #[test]
-    fn md5() {
-        name(Uuid::new_v3, Version::Md5);
-        let uuid = Uuid::new_v3(NAMESPACE_DNS, b"www.widgets.com");
-        assert_eq!(
-            uuid,
-            // From Appendix B, with errata 1352, since RFC is wrong.
-            // Because of course it is.
-            Uuid::from_str("3d813cbb-47fb-32ba-91df-831e1593ac29").unwrap()
-        )
+    fn order() {
+        let mut rng = Rng::from_seed([1; 32]);
+        let mut uuids: Vec<Uuid> = (0..64)
+            .map(|_| Uuid::new_v4_rng(&mut rng).erase())
+            .collect();
+        uuids.extend([
+            Uuid::nil().erase(),
+            Uuid::from_bytes([0xFF; 16]),
+            Uuid::from_bytes(RAW),
+        ]);
+        for a in &uuids {
+            for b in &uuids {
+                assert_eq!(a.cmp(b), a.to_bytes().cmp(&b.to_bytes()));
+                assert_eq!(a == b, a.to_bytes() == b.to_bytes());
+            }
+        }
     }
 
     #[test]
-    fn sha1() {
-        name(Uuid::new_v5, Version::Sha1)
+    fn timeuuid_order() {
+        let v1 = |ts, node| TimeUuidOrd::new(Uuid::new_v1(ts, 0, node));
+        let mut ids = [
+            v1(0x1_0000_0000, [0; 6]),
+            v1(0xFFFF_FFFF, [0; 6]),
+            v1(0xFFFF_FFFF, [0x80; 6]),
+            v1(0x1_0000_0000_0000, [0; 6]),
+        ];
+        ids.sort();
+        assert_eq!(
+            ids,
+            [
+                v1(0xFFFF_FFFF, [0x80; 6]),
+                v1(0xFFFF_FFFF, [0; 6]),
+                v1(0x1_0000_0000, [0; 6]),
+                v1(0x1_0000_0000_0000, [0; 6]),
+            ]
+        );
     }
 
     #[test]
-    fn parse_string() {
-        let test = &[UUID_V4, UUID_V4_URN, UUID_V4_BRACED, UUID_V4_SIMPLE];
-        for uuid in test {
-            println!("Source UUID: {}", uuid);
-            let uuid = Uuid::from_str(&uuid.to_ascii_lowercase()).unwrap();
-            println!("Parsed UUID: {}\n", uuid);
-            assert_eq!(RAW, uuid.to_bytes(), "Parsed UUID bytes don't match");
+    fn sql_server_sequential() {
+        let mut gen = SqlServerSequential::new(Rng::from_seed([1; 32]));
+        let mut prev = gen.next(1000);
+        for ts in [1000, 999, 2000, 0] {
+            let next = gen.next(ts);
+            assert_eq!(prev.cmp_sql_server(next), Ordering::Less);
+            prev = next;
         }
 
-        for uuid in test {
-            println!("Source UUID: {}", uuid);
-            let uuid = Uuid::from_str(&uuid.to_ascii_uppercase()).unwrap();
-            println!("Parsed UUID: {}\n", uuid);
-            assert_eq!(RAW, uuid.to_bytes(), "Parsed UUID bytes don't match");
+        // Timestamps past 48 bits clamp, and the sequence saturates
+        let seq = |uuid: Uuid| {
+            let b = uuid.to_bytes();
+            u64::from_be_bytes([b[10], b[11], b[12], b[13], b[14], b[15], b[8], b[9]])
+        };
+        let mut gen = SqlServerSequential::new(Rng::from_seed([1; 32]));
+        assert_eq!(seq(gen.next(u64::MAX)), 0xFFFF_FFFF_FFFF_0000);
+        assert_eq!(seq(gen.next(1 << 48)), 0xFFFF_FFFF_FFFF_0001);
+        for _ in 0..0xFFFE {
+            gen.next(u64::MAX);
+        }
+        assert_eq!(seq(gen.next(0)), u64::MAX);
+        assert_eq!(seq(gen.next(0)), u64::MAX);
+
+        // Known SQL Server order, each of which sorts after the previous
+        let order = [
+            "01000000-0000-0000-0000-000000000000",
+            "00010000-0000-0000-0000-000000000000",
+            "00000100-0000-0000-0000-000000000000",
+            "00000001-0000-0000-0000-000000000000",
+            "00000000-0100-0000-0000-000000000000",
+            "00000000-0001-0000-0000-000000000000",
+            "00000000-0000-0100-0000-000000000000",
+            "00000000-0000-0001-0000-000000000000",
+            "00000000-0000-0000-0001-000000000000",
+            "00000000-0000-0000-0100-000000000000",
+            "00000000-0000-0000-0000-000000000001",
+            "00000000-0000-0000-0000-010000000000",
+        ];
+        for w in order.windows(2) {
+            let (a, b) = (Uuid::parse(w[0]).unwrap(), Uuid::parse(w[1]).unwrap());
+            assert_eq!(a.cmp_sql_server(b), Ordering::Less, "{a} < {b}");
         }
     }
 
     #[test]
-    fn string() {
+    fn key_bytes() {
+        let mut rng = Rng::from_seed([2; 32]);
+        let mut v1s = Vec::new();
+        for _ in 0..100 {
+            let uuid = Uuid::new_v4_rng(&mut rng).erase();
+            assert_eq!(uuid.to_key_bytes(KeyOrder::Time), uuid.to_bytes());
+
+            let mut bytes = [0; 16];
+            rng.fill_bytes(&mut bytes);
+            let ts = u64::from_le_bytes(bytes[..8].try_into().unwrap()) >> 4;
+            let counter = u16::from_le_bytes([bytes[14], bytes[15]]);
+            let v1 = Uuid::new_v1(ts, counter, bytes[8..14].try_into().unwrap());
+            let key = v1.to_key_bytes(KeyOrder::Time);
+            assert_eq!(Uuid::from_key_bytes(key, KeyOrder::Time), v1);
+            v1s.push((ts, key));
+        }
+        v1s.sort_by_key(|&(_, key)| key);
+        assert!(v1s.windows(2).all(|w| w[0].0 <= w[1].0));
+    }
+
+    #[test]
+    fn mysql_bin() {
         let uuid = Uuid::from_bytes(RAW);
-        let mut buf = [0; 45];
-        assert_eq!(
-            uuid.to_str((&mut buf[..36]).try_into().unwrap()),
-            UUID_V4,
-            "UUID strings didn't match"
-        );
-        assert_eq!(
-            uuid.to_urn(&mut buf),
-            UUID_V4_URN,
-            "UUID URN strings didn't match"
+        assert_eq!(uuid.to_mysql_bin(false), RAW);
+        assert_eq!(Uuid::from_mysql_bin(RAW, false), uuid);
+        assert_eq!(Uuid::from_mysql_bin(uuid.to_mysql_bin(true), true), uuid);
+
+        // Swapped V1 UUIDs sort by time
+        let early = Uuid::new_v1(0xFFFF_FFFF, 0, [0; 6]);
+        let late = Uuid::new_v1(0x1_0000_0000, 0, [0; 6]);
+        assert!(early > late);
+        assert!(early.to_mysql_bin(true) < late.to_mysql_bin(true));
+    }
+
+    #[test]
+    fn defs_namespace() {
+        use crate::defs::namespace;
+
+        crate::namespace!(TEST, "662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+        assert_eq!(TEST.get(), Uuid::from_bytes(RAW));
+        crate::namespace!(
+            /// Braced
+            BRACED, "{6ba7b810-9dad-11d1-80b4-00c04fd430c8}"
         );
-        assert_eq!(
-            uuid.to_urn_upper(&mut buf),
-            UUID_V4_URN_UPPER,
-            "UUID URN upper strings didn't match"
+        assert_eq!(BRACED, NAMESPACE_DNS);
+
+        for (ns, s) in [
+            (namespace::CHID, "70ffd812-4c7f-4c7d-0000-000000000000"),
+            (
+                namespace::EVENT_SOURCE,
+                "482c2db2-c390-47c8-87f8-1a15bfc130fb",
+            ),
+        ] {
+            assert_eq!(ns.get(), Uuid::parse(s).unwrap());
+        }
+
+        // From the .NET runtime's own providers
+        for (name, s) in [
+            ("System.Runtime", "49592c0f-5a05-516d-aa4b-a64e02026c89"),
+            (
+                "Microsoft-Diagnostics-DiagnosticSource",
+                "adb401e1-5296-51f8-c125-5fda75826144",
+            ),
+            (
+                "microsoft-diagnostics-diagnosticsource",
+                "adb401e1-5296-51f8-c125-5fda75826144",
+            ),
+        ] {
+            assert_eq!(
+                namespace::event_source_guid(name),
+                Uuid::parse(s).unwrap(),
+                "{name}"
+            );
+        }
+        let guid = namespace::event_source_guid("MyCompany-MyComponent");
+        assert_eq!(guid.version(), Version::Sha1);
+        assert_ne!(
+            guid,
+            Uuid::new_v5(namespace::EVENT_SOURCE, b"MyCompany-MyComponent")
         );
+    }
+
+    #[test]
+    fn defs_efi() {
+        use crate::defs::efi;
+
+        for (uuid, s) in [
+            (efi::GLOBAL_VARIABLE, "8be4df61-93ca-11d2-aa0d-00e098032b8c"),
+            (
+                efi::IMAGE_SECURITY_DATABASE,
+                "d719b2cb-3d3a-4596-a3bc-dad00e67656f",
+            ),
+            (efi::CERT_SHA256, "c1c41626-504c-4092-aca9-41f936934328"),
+            (efi::CERT_RSA2048, "3c5766e8-269c-4e34-aa14-ed776e85b3b6"),
+            (efi::CERT_X509, "a5c059a1-94e4-4aa7-87b5-ab155c2bf072"),
+            (efi::CERT_TYPE_PKCS7, "4aafd29d-68df-49ee-8aa9-347d375665a7"),
+            (
+                efi::LOADED_IMAGE_PROTOCOL,
+                "5b1b31a1-9562-11d2-8e3f-00a0c969723b",
+            ),
+            (
+                efi::DEVICE_PATH_PROTOCOL,
+                "09576e91-6d3f-11d2-8e39-00a0c969723b",
+            ),
+            (
+                efi::SIMPLE_FILE_SYSTEM_PROTOCOL,
+                "964e5b22-6459-11d2-8e39-00a0c969723b",
+            ),
+            (efi::FILE_INFO, "09576e92-6d3f-11d2-8e39-00a0c969723b"),
+            (
+                efi::BLOCK_IO_PROTOCOL,
+                "964e5b21-6459-11d2-8e39-00a0c969723b",
+            ),
+            (
+                efi::SIMPLE_TEXT_INPUT_PROTOCOL,
+                "387477c1-69c7-11d2-8e39-00a0c969723b",
+            ),
+            (
+                efi::SIMPLE_TEXT_OUTPUT_PROTOCOL,
+                "387477c2-69c7-11d2-8e39-00a0c969723b",
+            ),
+            (
+                efi::GRAPHICS_OUTPUT_PROTOCOL,
+                "9042a9de-23dc-4a38-96fb-7aded080516a",
+            ),
+            (efi::RNG_PROTOCOL, "3152bca5-eade-433d-862e-c01cdc291f44"),
+            (efi::ACPI_20_TABLE, "8868e871-e4f1-11d3-bc22-0080c73c8881"),
+            (efi::SMBIOS3_TABLE, "f2fd1544-9794-4a2c-992e-e5bbcf20e394"),
+            (
+                efi::SYSTEM_PARTITION,
+                "c12a7328-f81f-11d2-ba4b-00a0c93ec93b",
+            ),
+        ] {
+            assert_eq!(uuid, Uuid::parse(s).unwrap());
+        }
+    }
+
+    #[test]
+    fn ct_eq() {
+        let uuid = Uuid::from_bytes(RAW);
+        assert!(uuid.ct_eq(Uuid::from_bytes(RAW)));
+        for i in 0..16 {
+            let mut raw = RAW;
+            raw[i] ^= 1;
+            assert!(!uuid.ct_eq(Uuid::from_bytes(raw)));
+        }
+        #[cfg(feature = "subtle")]
+        {
+            use subtle::ConstantTimeEq;
+            assert!(bool::from(ConstantTimeEq::ct_eq(&uuid, &uuid)));
+            assert!(!bool::from(ConstantTimeEq::ct_eq(
+                &uuid,
+                &Uuid::nil().erase()
+            )));
+        }
+    }
+
+    #[test]
+    fn secret_uuid() {
+        let uuid = Uuid::from_bytes(RAW);
+        let secret = SecretUuid::from(uuid);
+        assert_eq!(secret.expose(), uuid);
+        assert_eq!(secret, SecretUuid::new(uuid));
+        assert_ne!(secret, SecretUuid::new(Uuid::nil()));
+        assert_eq!(secret.to_string(), "662aa7c7-…");
+        assert_eq!(format!("{secret:#}"), "[REDACTED]");
+        assert_eq!(format!("{secret:?}"), "SecretUuid(662aa7c7-…)");
+        assert_eq!(format!("{secret:#?}"), "SecretUuid([REDACTED])");
+        assert_eq!(UUID_V4_URN.parse::<SecretUuid>().unwrap(), secret);
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn zeroize() {
+        use zeroize::Zeroize;
+
+        let mut uuid = Uuid::new_v4_rng(&mut Rng::from_seed([1; 32]));
+        uuid.zeroize();
+        assert!(uuid.is_nil());
+
+        let mut rng = Rng::from_seed([1; 32]);
+        rng.zeroize();
+        let used = std::panic::catch_unwind(move || Uuid::new_v4_rng(&mut rng));
+        assert!(used.is_err());
+
+        #[cfg(feature = "getrandom")]
+        {
+            let mut rng = Rng::from_seed([1; 32]);
+            rng.zeroize();
+            rng.reseed().unwrap();
+            assert!(!Uuid::new_v4_rng(&mut rng).is_nil());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "critical-section")]
+    fn global_rng() {
+        use crate::global;
+
+        let mut rng = Rng::from_seed([1; 32]);
+        global::init(rng.clone());
+        assert!(global::is_init());
+        assert_eq!(global::new_v4(), Some(Uuid::new_v4_rng(&mut rng)));
+        #[cfg(feature = "experimental_uuid")]
+        assert_eq!(global::new_v7(42), Some(Uuid::new_v7_rng(42, &mut rng)));
         assert_eq!(
-            format!("{:#x}", uuid),
-            UUID_V4_URN,
-            "UUID URN Display didn't match"
+            global::with_rng(Uuid::new_v4_rng),
+            Some(Uuid::new_v4_rng(&mut rng))
         );
-        assert_eq!(format!("{:x}", uuid), UUID_V4, "UUID Display didn't match");
+    }
+
+    #[test]
+    fn com() {
+        use crate::com::{Clsid, Iid, ProgIdGuid};
+
+        let uuid = Uuid::from_bytes(RAW);
+        let mut buf = [0; 48];
+        let iid = Iid::new(uuid);
+        assert_eq!(iid.guid(), Guid::from(uuid));
+        assert_eq!(iid.to_string(), format!("{{{uuid}}}"));
+        assert_eq!(format!("{iid:?}"), format!("Iid({{{uuid}}})"));
+
+        let path = iid.to_registry_path(&mut buf).to_owned();
+        assert_eq!(path, format!("Interface\\{{{uuid}}}"));
+        assert_eq!(Iid::from_registry_path(&path).unwrap(), iid);
+        assert_eq!(Iid::from_registry_path(&path.to_lowercase()).unwrap(), iid);
+        assert!(Clsid::from_registry_path(&path).is_err());
+        assert!(Iid::from_registry_path(&format!("Interface\\{UUID_V4}")).is_err());
+
+        let clsid: Clsid = UUID_V4_BRACED.parse().unwrap();
+        assert_eq!(clsid.get(), uuid);
+        let prog = ProgIdGuid::from(uuid);
+        assert_eq!(Clsid::from(prog), clsid);
+        assert_eq!(prog.to_string(), clsid.to_string());
+
+        const CLSID: Clsid = Clsid::new(NAMESPACE_DNS.get());
+        assert_eq!(CLSID.get(), NAMESPACE_DNS.get());
+    }
+
+    #[test]
+    fn duid() {
+        let uuid = Uuid::from_bytes(RAW);
+        let bytes = Duid::new(uuid).to_bytes();
+        assert_eq!(bytes[..2], [0, 4]);
+        assert_eq!(bytes[2..], RAW);
+        assert_eq!(Duid::try_from(&bytes[..]).map(Uuid::from), Ok(uuid));
+        assert_eq!(Duid::from_bytes(&bytes[..17]), Err(NuuidError::InvalidDuid));
         assert_eq!(
-            format!("{}", uuid),
-            UUID_V4.to_ascii_uppercase(),
-            "UUID Display didn't match"
+            Duid::from_bytes(&[bytes.as_slice(), &[0]].concat()),
+            Err(NuuidError::InvalidDuid)
         );
         assert_eq!(
-            format!("{}", Uuid::nil()),
-            UUID_NIL,
-            "Nil UUID Display didn't work!"
+            Duid::from_bytes(&[&[0, 3], &RAW[..]].concat()),
+            Err(NuuidError::InvalidDuid)
         );
     }
 
     #[test]
-    fn endian() {
-        let uuid_be = Uuid::from_bytes(RAW);
-        assert_eq!(uuid_be.version(), Version::Random);
-        assert_eq!(uuid_be.variant(), Variant::Rfc4122);
+    fn smbios() {
+        let uuid = Uuid::from_bytes(RAW);
+        assert_eq!(Uuid::from_smbios(uuid.to_bytes_me(), 2, 6), Some(uuid));
+        assert_eq!(Uuid::from_smbios(uuid.to_bytes_me(), 3, 0), Some(uuid));
+        assert_eq!(Uuid::from_smbios(RAW, 2, 5), Some(uuid));
+        assert_eq!(Uuid::from_smbios(RAW, 1, 9), Some(uuid));
+        assert_eq!(Uuid::from_smbios([0; 16], 3, 0), None);
+        assert_eq!(Uuid::from_smbios([0xFF; 16], 2, 5), None);
+    }
 
-        let uuid_le = Uuid::from_bytes_me(uuid_be.to_bytes_me());
-        assert_eq!(uuid_le.version(), Version::Random);
-        assert_eq!(uuid_le.variant(), Variant::Rfc4122);
+    #[test]
+    fn ad_object_guid() {
+        let uuid = Uuid::from_bytes(RAW);
+        let guid = uuid.to_ad_object_guid();
+        assert_eq!(guid, uuid.to_bytes_me());
+        assert_eq!(Uuid::from_ad_object_guid(&guid), uuid);
 
-        assert_eq!(uuid_le, uuid_be);
-        assert_ne!(uuid_be.to_bytes_me(), uuid_be.to_bytes());
+        let mut buf = [0; 48];
+        let filter = uuid.as_ldap_filter(&mut buf);
+        assert_eq!(filter.len(), 48);
+        assert!(filter.starts_with(r"\c7\a7\2a\66"));
+        assert!(filter.ends_with(r"\f9\98\a2"));
+    }
 
-        // Terrible UUID sourced from my partition table on Linux.
-        // Either Linux displays them wrong, or parted stores them wrong.
-        // Either way, its swapped.
-        const UUID: &str = "20169084-b186-884f-b110-3db2c37eb8b5";
-        let uuid = Uuid::parse_me(UUID).unwrap();
-        let bad_uuid = Uuid::parse(UUID).unwrap();
+    #[test]
+    fn bson_legacy() {
+        let uuid = Uuid::from_bytes(RAW);
+        for flavor in [BsonFlavor::Java, BsonFlavor::CSharp, BsonFlavor::Python] {
+            assert_eq!(
+                Uuid::from_bson_legacy(uuid.to_bson_legacy(flavor), flavor),
+                uuid
+            );
+        }
+        assert_eq!(uuid.to_bson_legacy(BsonFlavor::Python), RAW);
+        assert_eq!(uuid.to_bson_legacy(BsonFlavor::CSharp), uuid.to_bytes_me());
+        let mut java = RAW;
+        java[..8].reverse();
+        java[8..].reverse();
+        assert_eq!(uuid.to_bson_legacy(BsonFlavor::Java), java);
+    }
 
-        assert_ne!(bad_uuid.version(), Version::Random);
-        assert_eq!(uuid.version(), Version::Random);
-        assert_eq!(uuid.variant(), Variant::Rfc4122);
-        // Cant be equal because endian
-        assert_ne!(uuid.to_str(&mut [0; 36]), UUID);
+    #[test]
+    #[cfg(feature = "capi")]
+    fn capi() {
+        use crate::capi::*;
+        use core::ptr::{null, null_mut};
+
+        let mut out = NuuidUuid::default();
+        let mut str = NuuidStr::default();
+        unsafe {
+            assert_eq!(nuuid_new_v4(&mut out), NuuidStatus::Ok);
+            assert_eq!(Uuid::from(out).version(), Version::Random);
+            assert_eq!(nuuid_new_v4(null_mut()), NuuidStatus::NullPointer);
+
+            let s = UUID_V4.as_ptr().cast();
+            assert_eq!(nuuid_parse(s, 36, &mut out), NuuidStatus::Ok);
+            assert_eq!(out.bytes, RAW);
+            assert_eq!(nuuid_parse(s, 35, &mut out), NuuidStatus::ParseError);
+            assert_eq!(nuuid_parse(null(), 0, &mut out), NuuidStatus::NullPointer);
+
+            assert_eq!(nuuid_format(&out, &mut str), NuuidStatus::Ok);
+            assert_eq!(
+                core::ffi::CStr::from_ptr(str.buf.as_ptr()).to_str(),
+                Ok(UUID_V4)
+            );
+            assert_eq!(nuuid_format(&out, null_mut()), NuuidStatus::NullPointer);
+
+            #[cfg(feature = "experimental_uuid")]
+            {
+                assert_eq!(nuuid_v7(1000, &mut out), NuuidStatus::Ok);
+                assert_eq!(Uuid::from(out).unix_ts_ms(), Some(1000));
+            }
+        }
     }
 
     #[test]
-    fn info() {
+    #[cfg(feature = "id128")]
+    fn id128() {
+        let base = Uuid::from_bytes(RAW);
+        let app = |n| id128::app_specific(base, Uuid::from_u128(n));
+        assert_eq!(app(1), app(1));
+        assert_ne!(app(1), app(2));
+        assert_ne!(app(1), id128::app_specific(Uuid::nil(), Uuid::from_u128(1)));
+        if let Ok(machine) = id128::machine_id() {
+            assert_eq!(
+                id128::machine_app_specific(base).unwrap(),
+                id128::app_specific(machine, base)
+            );
+        }
+        if let Ok(boot) = id128::boot_id() {
+            assert_eq!(
+                id128::boot_app_specific(base).unwrap(),
+                id128::app_specific(boot, base)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rusqlite")]
+    fn rusqlite() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
         let uuid = Uuid::from_bytes(RAW);
-        assert_eq!(uuid.version(), Version::Random);
-        assert_eq!(uuid.variant(), Variant::Rfc4122);
-        #[cfg(feature = "getrandom")]
-        {
-            let uuid = Uuid::new_v4();
-            assert_eq!(uuid.version(), Version::Random);
-            assert_eq!(uuid.variant(), Variant::Rfc4122);
+        let get = |sql: &str| conn.query_row(sql, [], |row| row.get::<_, Uuid<RfcV4>>(0));
+
+        let kind: String = conn
+            .query_row("SELECT typeof(?1)", [uuid], |row| row.get(0))
+            .unwrap();
+        assert_eq!(kind, "blob");
+        let back: Uuid<RfcV4> = conn
+            .query_row("SELECT ?1", [uuid], |row| row.get(0))
+            .unwrap();
+        assert_eq!(back, uuid);
+
+        assert_eq!(get(&format!("SELECT '{UUID_V4_BRACED}'")).unwrap(), uuid);
+        assert!(get("SELECT x'00'").is_err());
+        assert!(get("SELECT 'not a uuid'").is_err());
+        assert!(get("SELECT 1").is_err());
+        assert!(get(&format!("SELECT '{}'", Uuid::new_v5(NAMESPACE_DNS, b"x"))).is_err());
+    }
+
+    #[cfg(all(feature = "proptest", feature = "experimental_uuid"))]
+    ::proptest::proptest! {
+        #[test]
+        fn proptest_v7(uuid in crate::proptest::uuid_v7_in_range(1000..2000)) {
+            let ms = uuid.unix_ts_ms().unwrap();
+            ::proptest::prop_assert!((1000..2000).contains(&ms));
         }
     }
 }
@@ -0,0 +1,293 @@
+//! Macros
+
+/// Create a [`Uuid`](crate::Uuid) from a string literal, at compile time.
+///
+/// This accepts the hyphenated, simple, braced, and URN formats,
+/// so UUIDs can be copied exactly as written in specifications.
+///
+/// Invalid UUIDs are a compile error, describing what was wrong.
+/// With the `macros` feature, this is a procedural macro, and the error
+/// also points to the invalid character, where the compiler supports it.
+///
+/// See [`Uuid::parse_const`](crate::Uuid::parse_const) for details.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{uuid, Uuid};
+/// const UUID: Uuid = uuid!("662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+/// assert_eq!(UUID, Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap());
+///
+/// assert_eq!(uuid!("{662aa7c7-7598-4d56-8bcc-a72c30f998a2}"), UUID);
+/// assert_eq!(uuid!("urn:uuid:662aa7c7-7598-4d56-8bcc-a72c30f998a2"), UUID);
+/// assert_eq!(uuid!("662aa7c775984d568bcca72c30f998a2"), UUID);
+/// ```
+///
+/// ```rust,compile_fail
+/// # use nuuid::uuid;
+/// let uuid = uuid!("662aa7c7-7598-4d56-8bcc-a72c30f998ag");
+/// ```
+///
+/// ```rust,compile_fail
+/// # use nuuid::uuid;
+/// let uuid = uuid!("{662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+/// ```
+#[cfg(not(feature = "macros"))]
+#[macro_export]
+macro_rules! uuid {
+    ($s:literal) => {{
+        const UUID: $crate::Uuid = match $crate::Uuid::parse_const($s) {
+            ::core::result::Result::Ok(uuid) => uuid,
+            ::core::result::Result::Err(e) => ::core::panic!("{}", e.__const_message()),
+        };
+        UUID
+    }};
+}
+
+// Documented above
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! uuid {
+    ($s:literal) => {{
+        const UUID: $crate::Uuid = $crate::__uuid!($crate, $s);
+        UUID
+    }};
+}
+
+/// Define a [`Namespace`](crate::Namespace) constant from a string literal,
+/// checked at compile time.
+///
+/// This accepts the same formats as [`uuid!`], and any attributes,
+/// such as documentation, and visibility.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{namespace, Uuid};
+/// namespace!(
+///     /// Our user IDs
+///     pub USERS, "662aa7c7-7598-4d56-8bcc-a72c30f998a2"
+/// );
+/// let uuid = Uuid::new_v5(USERS, b"user:1234");
+/// ```
+///
+/// ```rust,compile_fail
+/// # use nuuid::namespace;
+/// namespace!(USERS, "662aa7c7-7598-4d56-8bcc-a72c30f998");
+/// ```
+#[macro_export]
+macro_rules! namespace {
+    ($(#[$meta:meta])* $vis:vis $name:ident, $s:literal) => {
+        $(#[$meta])*
+        $vis const $name: $crate::Namespace = $crate::Namespace::new($crate::uuid!($s));
+    };
+}
+
+/// Implementation of the version checked literal macros, such as [`uuid_v4!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __uuid_versioned {
+    ($state:ty, $s:literal) => {{
+        const UUID: $crate::Uuid<$state> = match $crate::uuid!($s).__const_versioned() {
+            ::core::result::Result::Ok(uuid) => uuid,
+            ::core::result::Result::Err(e) => ::core::panic!("{}", e.__const_message()),
+        };
+        UUID
+    }};
+}
+
+/// Create a Version 1 [`Uuid`](crate::Uuid) from a string literal,
+/// at compile time.
+///
+/// UUIDs which are invalid, or not Version 1, are a compile error.
+///
+/// See [`uuid!`] for the accepted formats.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{state::RfcV1, uuid_v1, Uuid};
+/// const UUID: Uuid<RfcV1> = uuid_v1!("6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+/// ```
+#[macro_export]
+macro_rules! uuid_v1 {
+    ($s:literal) => {
+        $crate::__uuid_versioned!($crate::state::RfcV1, $s)
+    };
+}
+
+/// Create a Version 2 [`Uuid`](crate::Uuid) from a string literal,
+/// at compile time.
+///
+/// UUIDs which are invalid, or not Version 2, are a compile error.
+///
+/// See [`uuid!`] for the accepted formats.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{state::RfcV2, uuid_v2, Uuid};
+/// const UUID: Uuid<RfcV2> = uuid_v2!("000003e8-9dad-21d1-8000-00c04fd430c8");
+/// ```
+#[macro_export]
+macro_rules! uuid_v2 {
+    ($s:literal) => {
+        $crate::__uuid_versioned!($crate::state::RfcV2, $s)
+    };
+}
+
+/// Create a Version 3 [`Uuid`](crate::Uuid) from a string literal,
+/// or from a namespace and name, at compile time.
+///
+/// UUIDs which are invalid, or not Version 3, are a compile error.
+///
+/// See [`uuid!`] for the accepted formats, and
+/// [`Uuid::new_v3_const`](crate::Uuid::new_v3_const) for name based UUIDs.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{state::RfcV3, uuid_v3, Uuid, NAMESPACE_DNS};
+/// const UUID: Uuid<RfcV3> = uuid_v3!("5df41881-3aed-3515-88a7-2f4a814cf09e");
+/// const NAMED: Uuid<RfcV3> = uuid_v3!(NAMESPACE_DNS, "example.org");
+/// assert_eq!(NAMED, Uuid::new_v3(NAMESPACE_DNS, b"example.org"));
+/// ```
+#[macro_export]
+macro_rules! uuid_v3 {
+    ($s:literal) => {
+        $crate::__uuid_versioned!($crate::state::RfcV3, $s)
+    };
+    ($namespace:expr, $name:literal) => {{
+        const UUID: $crate::Uuid<$crate::state::RfcV3> =
+            $crate::Uuid::new_v3_const($namespace, $name.as_bytes());
+        UUID
+    }};
+}
+
+/// Create a Version 4 [`Uuid`](crate::Uuid) from a string literal,
+/// at compile time.
+///
+/// UUIDs which are invalid, or not Version 4, are a compile error.
+///
+/// See [`uuid!`] for the accepted formats.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{state::RfcV4, uuid_v4, Uuid};
+/// const UUID: Uuid<RfcV4> = uuid_v4!("662aa7c7-7598-4d56-8bcc-a72c30f998a2");
+/// ```
+///
+/// ```rust,compile_fail
+/// # use nuuid::uuid_v4;
+/// // Version 1
+/// let uuid = uuid_v4!("6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+/// ```
+#[macro_export]
+macro_rules! uuid_v4 {
+    ($s:literal) => {
+        $crate::__uuid_versioned!($crate::state::RfcV4, $s)
+    };
+}
+
+/// Create a Version 5 [`Uuid`](crate::Uuid) from a string literal,
+/// or from a namespace and name, at compile time.
+///
+/// UUIDs which are invalid, or not Version 5, are a compile error.
+///
+/// See [`uuid!`] for the accepted formats, and
+/// [`Uuid::new_v5_const`](crate::Uuid::new_v5_const) for name based UUIDs.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{state::RfcV5, uuid_v5, Uuid, NAMESPACE_DNS};
+/// const UUID: Uuid<RfcV5> = uuid_v5!("2ed6657d-e927-568b-95e1-2665a8aea6a2");
+/// const NAMED: Uuid<RfcV5> = uuid_v5!(NAMESPACE_DNS, "example.org");
+/// assert_eq!(NAMED, Uuid::new_v5(NAMESPACE_DNS, b"example.org"));
+/// ```
+#[macro_export]
+macro_rules! uuid_v5 {
+    ($s:literal) => {
+        $crate::__uuid_versioned!($crate::state::RfcV5, $s)
+    };
+    ($namespace:expr, $name:literal) => {{
+        const UUID: $crate::Uuid<$crate::state::RfcV5> =
+            $crate::Uuid::new_v5_const($namespace, $name.as_bytes());
+        UUID
+    }};
+}
+
+/// Create a Version 6 [`Uuid`](crate::Uuid) from a string literal,
+/// at compile time.
+///
+/// UUIDs which are invalid, or not Version 6, are a compile error.
+///
+/// This requires the `experimental_uuid` feature.
+///
+/// See [`uuid!`] for the accepted formats.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{state::RfcV6, uuid_v6, Uuid};
+/// const UUID: Uuid<RfcV6> = uuid_v6!("1ec9414c-232a-6b00-b3c8-9e6bdeced846");
+/// ```
+#[cfg(feature = "experimental_uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+#[macro_export]
+macro_rules! uuid_v6 {
+    ($s:literal) => {
+        $crate::__uuid_versioned!($crate::state::RfcV6, $s)
+    };
+}
+
+/// Create a Version 7 [`Uuid`](crate::Uuid) from a string literal,
+/// at compile time.
+///
+/// UUIDs which are invalid, or not Version 7, are a compile error.
+///
+/// This requires the `experimental_uuid` feature.
+///
+/// See [`uuid!`] for the accepted formats.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{state::RfcV7, uuid_v7, Uuid};
+/// const UUID: Uuid<RfcV7> = uuid_v7!("017f22e2-79b0-7cc3-98c4-dc0c0c07398f");
+/// ```
+#[cfg(feature = "experimental_uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+#[macro_export]
+macro_rules! uuid_v7 {
+    ($s:literal) => {
+        $crate::__uuid_versioned!($crate::state::RfcV7, $s)
+    };
+}
+
+/// Create a Version 8 [`Uuid`](crate::Uuid) from a string literal,
+/// at compile time.
+///
+/// UUIDs which are invalid, or not Version 8, are a compile error.
+/// The result has the default [`AnyLayout`](crate::state::AnyLayout).
+///
+/// This requires the `experimental_uuid` feature.
+///
+/// See [`uuid!`] for the accepted formats.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{state::RfcV8, uuid_v8, Uuid};
+/// const UUID: Uuid<RfcV8> = uuid_v8!("320c3d4d-cc00-875b-8ec9-32d5f69181c0");
+/// ```
+#[cfg(feature = "experimental_uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+#[macro_export]
+macro_rules! uuid_v8 {
+    ($s:literal) => {
+        $crate::__uuid_versioned!($crate::state::RfcV8, $s)
+    };
+}
@@ -0,0 +1,61 @@
+//! Proptest strategies
+//!
+//! This requires the `proptest` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use nuuid::{proptest::uuid_v4, Version};
+//! # use proptest::{prop_assert_eq, proptest};
+//! proptest!(|(uuid in uuid_v4())| {
+//!     prop_assert_eq!(uuid.version(), Version::Random);
+//! });
+//! ```
+#[cfg(feature = "experimental_uuid")]
+use crate::state::RfcV7;
+use crate::{
+    state::{RfcV4, VersionedUuid},
+    Uuid,
+};
+use ::proptest::{
+    prelude::{any, Just, Strategy},
+    prop_oneof,
+};
+#[cfg(feature = "experimental_uuid")]
+use core::ops::Range;
+
+/// Any [`Uuid`], including the nil and max UUIDs as edge cases.
+pub fn any_uuid() -> impl Strategy<Value = Uuid> {
+    prop_oneof![
+        1 => Just(Uuid::from_bytes([0; 16])),
+        1 => Just(Uuid::from_bytes([0xFF; 16])),
+        8 => any::<[u8; 16]>().prop_map(Uuid::from_bytes),
+    ]
+}
+
+/// Any [`Uuid`] valid for the state `S`.
+///
+/// The version and variant are set for `S`, and the rest is random.
+pub fn versioned<S: VersionedUuid>() -> impl Strategy<Value = Uuid<S>> {
+    any::<[u8; 16]>().prop_filter_map("UUID not valid for the state", |bytes| {
+        let mut uuid = Uuid::from_bytes(bytes);
+        uuid.set_version(S::VERSION);
+        uuid.set_variant(S::VARIANT);
+        uuid.try_cast()
+    })
+}
+
+/// Any Version 4 [`Uuid`].
+pub fn uuid_v4() -> impl Strategy<Value = Uuid<RfcV4>> {
+    versioned()
+}
+
+/// Any Version 7 [`Uuid`] with a unix millisecond timestamp in `range`.
+///
+/// See [`Uuid::new_v7`] for details.
+#[cfg(feature = "experimental_uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+pub fn uuid_v7_in_range(range: Range<u64>) -> impl Strategy<Value = Uuid<RfcV7>> {
+    (range, any::<u16>(), any::<u64>())
+        .prop_map(|(timestamp, rand_a, rand_b)| Uuid::new_v7(timestamp, rand_a, rand_b))
+}
@@ -0,0 +1,90 @@
+//! Prost support
+//!
+//! This requires the `prost` feature.
+//!
+//! UUIDs can be carried in `bytes` fields, using the [`Vec<u8>`] and
+//! [`Bytes`] conversions, or as a pair of `fixed64` fields using
+//! [`UuidMessage`].
+//!
+//! # Example
+//!
+//! ```rust
+//! # use nuuid::{NAMESPACE_DNS, Uuid};
+//! let bytes: Vec<u8> = NAMESPACE_DNS.get().into();
+//! assert_eq!(Uuid::try_from(bytes), Ok(NAMESPACE_DNS.get()));
+//! ```
+use crate::{state::State, NuuidError, Uuid};
+use ::prost::{bytes::Bytes, Message};
+use alloc::vec::Vec;
+
+/// A UUID as a Protocol Buffers message, of two `fixed64` fields.
+///
+/// This is compatible with the following message
+///
+/// ```protobuf
+/// message Uuid {
+///   fixed64 high = 1;
+///   fixed64 low = 2;
+/// }
+/// ```
+///
+/// See [`Uuid::from_u64_pair`] for details.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Message)]
+pub struct UuidMessage {
+    /// The high 64 bits.
+    #[prost(fixed64, tag = "1")]
+    pub high: u64,
+
+    /// The low 64 bits.
+    #[prost(fixed64, tag = "2")]
+    pub low: u64,
+}
+
+impl<S: State> From<Uuid<S>> for UuidMessage {
+    #[inline]
+    fn from(uuid: Uuid<S>) -> Self {
+        let (high, low) = uuid.to_u64_pair();
+        Self { high, low }
+    }
+}
+
+impl From<UuidMessage> for Uuid {
+    #[inline]
+    fn from(msg: UuidMessage) -> Self {
+        Uuid::from_u64_pair(msg.high, msg.low)
+    }
+}
+
+impl<S: State> From<Uuid<S>> for Vec<u8> {
+    #[inline]
+    fn from(uuid: Uuid<S>) -> Self {
+        uuid.to_bytes().to_vec()
+    }
+}
+
+impl TryFrom<Vec<u8>> for Uuid {
+    type Error = NuuidError;
+
+    /// See [`Uuid::from_slice`] for details.
+    #[inline]
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Uuid::from_slice(&bytes)
+    }
+}
+
+impl<S: State> From<Uuid<S>> for Bytes {
+    #[inline]
+    fn from(uuid: Uuid<S>) -> Self {
+        Bytes::copy_from_slice(&uuid.to_bytes())
+    }
+}
+
+impl TryFrom<Bytes> for Uuid {
+    type Error = NuuidError;
+
+    /// See [`Uuid::from_slice`] for details.
+    #[inline]
+    fn try_from(bytes: Bytes) -> Result<Self, Self::Error> {
+        Uuid::from_slice(&bytes)
+    }
+}
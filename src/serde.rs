@@ -0,0 +1,234 @@
+//! Serde support
+//!
+//! This requires the `serde` feature.
+//!
+//! [`Uuid`] serializes as a hyphenated lowercase string for human-readable
+//! formats, such as JSON, and as a 16 byte array for binary formats,
+//! such as bincode.
+//!
+//! Deserialization accepts both, as well as any format supported by
+//! [`Uuid::parse`].
+//!
+//! See [`lenient`] for accepting even more formats.
+//!
+//! Typed UUIDs, such as `Uuid<RfcV7>`, check their version when
+//! deserializing, and fail if it does not match.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use nuuid::Uuid;
+//! # use serde_test::{assert_tokens, Configure, Token};
+//! let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+//! assert_tokens(
+//!     &uuid.readable(),
+//!     &[Token::Str("662aa7c7-7598-4d56-8bcc-a72c30f998a2")],
+//! );
+//! ```
+use crate::{state::State, Bytes, ParseUuidError, Uuid};
+use ::serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use core::fmt;
+
+impl<S: State> Serialize for Uuid<S> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        if serializer.is_human_readable() {
+            let mut buf = [0; 36];
+            serializer.serialize_str(self.to_str(&mut buf))
+        } else {
+            self.0.serialize(serializer)
+        }
+    }
+}
+
+/// Typed UUIDs are checked against their state,
+/// see [`Uuid::into_state`].
+///
+/// # Errors
+///
+/// - If the UUID is not valid for the state `S`.
+impl<'de, S: State> Deserialize<'de> for Uuid<S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let uuid = if deserializer.is_human_readable() {
+            deserializer.deserialize_any(UuidVisitor { lenient: false })
+        } else {
+            deserializer.deserialize_tuple(16, UuidVisitor { lenient: false })
+        }?;
+        uuid.check_cast().map_err(de::Error::custom)
+    }
+}
+
+/// Accepts strings, bytes, and sequences of 16 bytes.
+///
+/// If `lenient`, also accepts integers, and more string formats.
+struct UuidVisitor {
+    lenient: bool,
+}
+
+impl<'de> Visitor<'de> for UuidVisitor {
+    type Value = Uuid;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.lenient {
+            write!(f, "a UUID string, integer, or 16 bytes")
+        } else {
+            write!(f, "a UUID string or 16 bytes")
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let uuid = if self.lenient {
+            let s = v.trim();
+            Uuid::parse(s)
+                .or_else(|_| Uuid::parse_ulid(s))
+                .or_else(|_| {
+                    s.parse()
+                        .map(Uuid::from_u128)
+                        .map_err(|_| ParseUuidError::invalid())
+                })
+        } else {
+            Uuid::parse(v)
+        };
+        uuid.map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.visit_u128(v.into())
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        if self.lenient {
+            Ok(Uuid::from_u128(v))
+        } else {
+            Err(E::invalid_type(de::Unexpected::Other("integer"), &self))
+        }
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Uuid::from_slice(v).map_err(|_| E::invalid_length(v.len(), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes: Bytes = [0; 16];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(17, &self));
+        }
+        Ok(Uuid::from_bytes(bytes))
+    }
+}
+
+/// Lenient deserialization, for inconsistent producers.
+///
+/// In addition to the formats [`Uuid`] normally accepts, this accepts
+///
+/// - Strings with surrounding whitespace
+/// - ULID strings, see [`Uuid::parse_ulid`]
+/// - Integers, and decimal integer strings, see [`Uuid::from_u128`]
+///
+/// Serialization is unchanged.
+///
+/// This requires a self-describing format, such as JSON.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::Uuid;
+/// # use serde::Deserialize;
+/// # use serde_test::{assert_de_tokens, Token};
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct User {
+///     #[serde(with = "nuuid::serde::lenient")]
+///     id: Uuid,
+/// }
+///
+/// let user = User { id: Uuid::from_u128(42) };
+/// let tokens = |id| [
+///     Token::Struct { name: "User", len: 1 },
+///     Token::Str("id"),
+///     id,
+///     Token::StructEnd,
+/// ];
+/// assert_de_tokens(&user, &tokens(Token::U64(42)));
+/// assert_de_tokens(&user, &tokens(Token::Str(" 0000000000000000000000001A ")));
+/// assert_de_tokens(&user, &tokens(Token::Str("00000000-0000-0000-0000-00000000002a")));
+/// ```
+pub mod lenient {
+    use super::*;
+
+    /// Serialize `uuid` normally, see [`Uuid`]s `Serialize`.
+    pub fn serialize<S: State, Ser: Serializer>(
+        uuid: &Uuid<S>,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error> {
+        uuid.serialize(serializer)
+    }
+
+    /// Deserialize a [`Uuid`] leniently.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uuid, D::Error> {
+        deserializer.deserialize_any(UuidVisitor { lenient: true })
+    }
+}
+
+/// Serialize [`None`] as the nil UUID, and the nil UUID as [`None`].
+///
+/// This is useful for APIs and databases that use the nil UUID to mean
+/// "missing".
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::Uuid;
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_test::{assert_tokens, Configure, Token};
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Post {
+///     #[serde(with = "nuuid::serde::nil_as_none")]
+///     parent: Option<Uuid>,
+/// }
+///
+/// assert_tokens(
+///     &Post { parent: None }.readable(),
+///     &[
+///         Token::Struct { name: "Post", len: 1 },
+///         Token::Str("parent"),
+///         Token::Str("00000000-0000-0000-0000-000000000000"),
+///         Token::StructEnd,
+///     ],
+/// );
+/// ```
+pub mod nil_as_none {
+    use super::*;
+
+    /// Serialize `uuid`, or the nil UUID if [`None`].
+    pub fn serialize<S: State, Ser: Serializer>(
+        uuid: &Option<Uuid<S>>,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error> {
+        match uuid {
+            Some(uuid) => uuid.serialize(serializer),
+            None => Uuid::nil().serialize(serializer),
+        }
+    }
+
+    /// Deserialize a [`Uuid`], or [`None`] if nil.
+    ///
+    /// # Errors
+    ///
+    /// - If the UUID is not nil and not valid for the state `S`.
+    pub fn deserialize<'de, S: State, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Uuid<S>>, D::Error> {
+        let uuid: Uuid = Uuid::deserialize(deserializer)?;
+        if uuid.is_nil() {
+            return Ok(None);
+        }
+        uuid.check_cast().map(Some).map_err(de::Error::custom)
+    }
+}
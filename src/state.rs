@@ -0,0 +1,351 @@
+//! UUID typestates
+//!
+//! A [`Uuid`] carries a state type parameter, which records
+//! what is statically known about it.
+//!
+//! UUIDs created from arbitrary bytes or strings are [`Unknown`], while
+//! UUIDs created by this library know their version, such as [`RfcV4`] for
+//! [`Uuid::new_v4`](crate::Uuid::new_v4).
+//!
+//! Untyped UUIDs can be checked into a typestate using [`TryFrom`].
+//!
+//! The states are sealed and cannot be implemented outside this crate,
+//! except for vendor specific [`RfcV8`] layouts, see [`V8Layout`].
+//!
+//! # Example
+//!
+//! ```rust
+//! # use nuuid::{state::RfcV4, Uuid};
+//! let uuid = Uuid::parse("662aa7c7-7598-4d56-8bcc-a72c30f998a2").unwrap();
+//! let uuid: Uuid<RfcV4> = uuid.try_into().unwrap();
+//! ```
+use crate::{NuuidError, Uuid, Variant, Version};
+use core::{fmt::Debug, hash::Hash};
+
+#[cfg(feature = "experimental_uuid")]
+use core::{any::type_name, cmp::Ordering, fmt, hash::Hasher, marker::PhantomData};
+
+mod private {
+    use crate::{NuuidError, Uuid};
+
+    pub trait Sealed {
+        /// Check that `uuid` is valid for this state.
+        fn check(uuid: Uuid) -> Result<(), NuuidError>;
+
+        /// Whether `uuid` is valid for this state.
+        #[inline]
+        fn is_valid(uuid: Uuid) -> bool {
+            Self::check(uuid).is_ok()
+        }
+
+        /// Whether alternate `Debug` should include decoded fields.
+        const DECODE: bool = false;
+    }
+}
+
+/// A [`Uuid`] typestate.
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait State: private::Sealed + Debug + Copy + Ord + Hash + 'static {}
+
+/// States of time based UUIDs, which have a timestamp.
+///
+/// This enables [`Uuid::timestamp`](crate::Uuid::timestamp) and
+/// [`Uuid::clock_sequence`](crate::Uuid::clock_sequence).
+pub trait HasTimestamp: State {}
+
+impl HasTimestamp for RfcV1 {}
+
+#[cfg(feature = "experimental_uuid")]
+impl HasTimestamp for RfcV6 {}
+
+#[cfg(feature = "experimental_uuid")]
+impl HasTimestamp for RfcV7 {}
+
+/// States of RFC UUIDs with a statically known version.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{state::VersionedUuid, Uuid, Version};
+/// fn version<V: VersionedUuid>(_: Uuid<V>) -> Version {
+///     V::VERSION
+/// }
+/// assert_eq!(version(Uuid::new_v1(0, 0, *b"world!")), Version::Time);
+/// ```
+pub trait VersionedUuid: State {
+    /// The UUID version.
+    const VERSION: Version;
+
+    /// The UUID variant, always [`Variant::Rfc4122`].
+    const VARIANT: Variant = Variant::Rfc4122;
+}
+
+impl VersionedUuid for RfcV1 {
+    const VERSION: Version = Version::Time;
+}
+
+impl VersionedUuid for RfcV2 {
+    const VERSION: Version = Version::Dce;
+}
+
+impl VersionedUuid for RfcV3 {
+    const VERSION: Version = Version::Md5;
+}
+
+impl VersionedUuid for RfcV4 {
+    const VERSION: Version = Version::Random;
+}
+
+impl VersionedUuid for RfcV5 {
+    const VERSION: Version = Version::Sha1;
+}
+
+#[cfg(feature = "experimental_uuid")]
+impl VersionedUuid for RfcV6 {
+    const VERSION: Version = Version::Database;
+}
+
+#[cfg(feature = "experimental_uuid")]
+impl VersionedUuid for RfcV7 {
+    const VERSION: Version = Version::UnixTime;
+}
+
+#[cfg(feature = "experimental_uuid")]
+impl<L: V8Layout> VersionedUuid for RfcV8<L> {
+    const VERSION: Version = Version::Vendor;
+}
+
+/// Check that `uuid` is an RFC UUID of version `ver`.
+#[inline]
+fn check_rfc(uuid: Uuid, ver: Version) -> Result<(), NuuidError> {
+    if uuid.variant() != Variant::Rfc4122 {
+        Err(NuuidError::WrongVariant)
+    } else if uuid.version() != ver {
+        Err(NuuidError::WrongVersion {
+            expected: ver,
+            found: uuid.version(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+macro_rules! state {
+    ($(#[$meta:meta])* $name:ident, $check:expr $(, decode = $decode:literal)?) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+        pub struct $name;
+
+        impl private::Sealed for $name {
+            #[inline]
+            fn check(uuid: Uuid) -> Result<(), NuuidError> {
+                let check: fn(Uuid) -> Result<(), NuuidError> = $check;
+                check(uuid)
+            }
+
+            $(const DECODE: bool = $decode;)?
+        }
+
+        impl State for $name {}
+    };
+}
+
+state!(
+    /// Nothing is known about the UUID.
+    ///
+    /// This is the default state, for UUIDs from bytes, strings, or other
+    /// untrusted sources.
+    Unknown,
+    |_| Ok(())
+);
+
+state!(
+    /// The special Nil UUID, where all bits are set to zero.
+    RfcNil,
+    |u| if u.is_nil() { Ok(()) } else { Err(NuuidError::NotNil) }
+);
+
+state!(
+    /// Version 1, time based.
+    RfcV1,
+    |u| check_rfc(u, Version::Time),
+    decode = true
+);
+
+state!(
+    /// Version 2, DCE Security.
+    RfcV2,
+    |u| check_rfc(u, Version::Dce),
+    decode = true
+);
+
+state!(
+    /// Version 3, MD5 name based.
+    RfcV3,
+    |u| check_rfc(u, Version::Md5)
+);
+
+state!(
+    /// Version 4, random.
+    RfcV4,
+    |u| check_rfc(u, Version::Random)
+);
+
+state!(
+    /// Version 5, SHA-1 name based.
+    RfcV5,
+    |u| check_rfc(u, Version::Sha1)
+);
+
+#[cfg(feature = "experimental_uuid")]
+state!(
+    /// Version 6, re-ordered version of [`RfcV1`] for DB locality.
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    RfcV6,
+    |u| check_rfc(u, Version::Database),
+    decode = true
+);
+
+#[cfg(feature = "experimental_uuid")]
+state!(
+    /// Version 7, unix time based.
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    RfcV7,
+    |u| check_rfc(u, Version::UnixTime),
+    decode = true
+);
+
+/// Version 8, experimental or vendor specific format.
+///
+/// The `L` parameter is the vendor specific [`V8Layout`],
+/// which defaults to [`AnyLayout`].
+#[cfg(feature = "experimental_uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+pub struct RfcV8<L: V8Layout = AnyLayout>(PhantomData<fn() -> L>);
+
+#[cfg(feature = "experimental_uuid")]
+impl<L: V8Layout> private::Sealed for RfcV8<L> {
+    #[inline]
+    fn check(uuid: Uuid) -> Result<(), NuuidError> {
+        check_rfc(uuid, Version::Vendor)?;
+        if L::is_valid(uuid) {
+            Ok(())
+        } else {
+            Err(NuuidError::InvalidLayout)
+        }
+    }
+}
+
+#[cfg(feature = "experimental_uuid")]
+impl<L: V8Layout> State for RfcV8<L> {}
+
+// Manual impls so `L` doesn't need to implement anything.
+
+#[cfg(feature = "experimental_uuid")]
+impl<L: V8Layout> Debug for RfcV8<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RfcV8<{}>", type_name::<L>())
+    }
+}
+
+#[cfg(feature = "experimental_uuid")]
+impl<L: V8Layout> Clone for RfcV8<L> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "experimental_uuid")]
+impl<L: V8Layout> Copy for RfcV8<L> {}
+
+#[cfg(feature = "experimental_uuid")]
+impl<L: V8Layout> PartialEq for RfcV8<L> {
+    #[inline]
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "experimental_uuid")]
+impl<L: V8Layout> Eq for RfcV8<L> {}
+
+#[cfg(feature = "experimental_uuid")]
+impl<L: V8Layout> PartialOrd for RfcV8<L> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "experimental_uuid")]
+impl<L: V8Layout> Ord for RfcV8<L> {
+    #[inline]
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+#[cfg(feature = "experimental_uuid")]
+impl<L: V8Layout> Hash for RfcV8<L> {
+    #[inline]
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+/// A vendor specific layout for [`RfcV8`] UUIDs.
+///
+/// Unlike [`State`], this trait can be implemented outside this crate,
+/// to give your own Version 8 sub-formats a distinct type, such as
+/// `Uuid<RfcV8<MyLayout>>`.
+///
+/// # Example
+///
+/// ```rust
+/// # use nuuid::{state::{RfcV8, V8Layout}, Uuid};
+/// /// Our layout, with a zero last byte.
+/// enum MyLayout {}
+///
+/// impl V8Layout for MyLayout {
+///     fn is_valid(uuid: Uuid) -> bool {
+///         uuid.to_bytes()[15] == 0
+///     }
+/// }
+///
+/// let uuid = Uuid::new_v8_layout::<MyLayout>(*b"I Am 16 bytes!!\0").unwrap();
+/// let uuid: Uuid = uuid.erase();
+/// assert!(uuid.into_state::<RfcV8<MyLayout>>().is_ok());
+/// assert!(Uuid::new_v8_layout::<MyLayout>(*b"I Am 16 bytes!!!").is_err());
+/// ```
+#[cfg(feature = "experimental_uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+pub trait V8Layout: 'static {
+    /// Whether `uuid` is valid for this layout.
+    ///
+    /// This is only called for Version 8 UUIDs,
+    /// the version and variant have already been checked.
+    ///
+    /// The default accepts every Version 8 UUID.
+    #[inline]
+    fn is_valid(uuid: Uuid) -> bool {
+        let _ = uuid;
+        true
+    }
+}
+
+/// The default [`V8Layout`], which accepts every Version 8 UUID.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg(feature = "experimental_uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+pub struct AnyLayout;
+
+#[cfg(feature = "experimental_uuid")]
+impl V8Layout for AnyLayout {}
+
+#[cfg(feature = "experimental_uuid")]
+state!(
+    /// The special Max UUID, where all bits are set to one.
+    #[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+    RfcMax,
+    |u| if u.is_max() { Ok(()) } else { Err(NuuidError::NotMax) }
+);
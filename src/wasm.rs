@@ -0,0 +1,179 @@
+//! WebAssembly support
+//!
+//! This requires the `wasm` feature.
+//!
+//! On `wasm32-unknown-unknown` in browsers and web workers, randomness comes
+//! from `crypto.getRandomValues`, so [`Uuid::new_v4`] and [`Rng::new`]
+//! work as normal.
+//! These panic if `crypto` is unavailable, which traps the WebAssembly
+//! instance, so prefer [`Uuid::try_new_v4`] and [`Rng::try_new`].
+//!
+//! The time comes from `performance.timeOrigin + performance.now()`,
+//! see [`unix_ms`].
+//!
+//! With the `wasm-class` feature, [`JsUuid`] is exported to JavaScript
+//! as the `Uuid` class.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # use nuuid::{wasm, Uuid};
+//! let uuid = Uuid::new_v4();
+//! let now = wasm::unix_ms();
+//! ```
+#[cfg(feature = "experimental_uuid")]
+use crate::{state::RfcV7, NuuidError, Rng, Uuid};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// The global `performance` object.
+    type Performance;
+
+    #[wasm_bindgen(thread_local_v2, js_name = performance)]
+    static PERFORMANCE: Performance;
+
+    #[wasm_bindgen(method, getter, js_name = timeOrigin)]
+    fn time_origin(this: &Performance) -> f64;
+
+    #[wasm_bindgen(method)]
+    fn now(this: &Performance) -> f64;
+}
+
+/// Milliseconds since the unix epoch, from the JavaScript `performance` clock.
+///
+/// # Panics
+///
+/// - If not running in a JavaScript environment with `performance`.
+#[inline]
+pub fn unix_ms() -> u64 {
+    PERFORMANCE.with(|p| (p.time_origin() + p.now()) as u64)
+}
+
+/// Create a new Version 7 UUID at the current time, see [`unix_ms`].
+///
+/// See [`Uuid::new_v7`] for details.
+///
+/// # Panics
+///
+/// - If `crypto` is unavailable, see [`try_new_v7`].
+#[cfg(feature = "experimental_uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+#[inline]
+pub fn new_v7() -> Uuid<RfcV7> {
+    Uuid::new_v7_rng(unix_ms(), &mut Rng::new())
+}
+
+/// Create a new Version 7 UUID at the current time, or an error if
+/// `crypto` is unavailable.
+///
+/// # Errors
+///
+/// - [`NuuidError::EntropyUnavailable`] if `crypto.getRandomValues` fails.
+#[cfg(feature = "experimental_uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental_uuid")))]
+#[inline]
+pub fn try_new_v7() -> Result<Uuid<RfcV7>, NuuidError> {
+    Rng::try_new().map(|mut rng| Uuid::new_v7_rng(unix_ms(), &mut rng))
+}
+
+#[cfg(feature = "wasm-class")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasm-class")))]
+pub use class::JsUuid;
+
+#[cfg(feature = "wasm-class")]
+mod class {
+    use crate::{NuuidError, Uuid};
+    use wasm_bindgen::prelude::*;
+
+    /// A [`Uuid`], exported to JavaScript as `Uuid`.
+    ///
+    /// This requires the `wasm-class` feature.
+    ///
+    /// ```js
+    /// import { Uuid } from "nuuid";
+    /// const id = Uuid.newV4().toString();
+    /// ```
+    #[wasm_bindgen(js_name = Uuid)]
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+    pub struct JsUuid(Uuid);
+
+    #[wasm_bindgen(js_class = Uuid)]
+    impl JsUuid {
+        /// A new random Version 4 UUID, see [`Uuid::try_new_v4`].
+        ///
+        /// # Errors
+        ///
+        /// - If `crypto` is unavailable, as a JavaScript `Error`.
+        #[wasm_bindgen(js_name = newV4)]
+        pub fn new_v4() -> Result<JsUuid, JsError> {
+            Uuid::try_new_v4()
+                .map(Self::from)
+                .map_err(|e| JsError::new(&e.to_string()))
+        }
+
+        /// A new Version 7 UUID at the current time, see [`super::try_new_v7`].
+        ///
+        /// # Errors
+        ///
+        /// - If `crypto` is unavailable, as a JavaScript `Error`.
+        #[cfg(feature = "experimental_uuid")]
+        #[wasm_bindgen(js_name = newV7)]
+        pub fn new_v7() -> Result<JsUuid, JsError> {
+            super::try_new_v7()
+                .map(Self::from)
+                .map_err(|e| JsError::new(&e.to_string()))
+        }
+
+        /// Parse a UUID string, see [`Uuid::parse`].
+        ///
+        /// # Errors
+        ///
+        /// - If `s` is not a valid UUID, as a JavaScript `Error`.
+        pub fn parse(s: &str) -> Result<JsUuid, JsError> {
+            Uuid::parse(s)
+                .map(Self)
+                .map_err(|e| JsError::new(&e.to_string()))
+        }
+
+        /// Create a UUID from 16 bytes, see [`Uuid::from_slice`].
+        ///
+        /// # Errors
+        ///
+        /// - If `bytes` is not 16 bytes, as a JavaScript `Error`.
+        #[wasm_bindgen(js_name = fromBytes)]
+        pub fn from_bytes(bytes: &[u8]) -> Result<JsUuid, JsError> {
+            Uuid::from_slice(bytes)
+                .map(Self)
+                .map_err(|e: NuuidError| JsError::new(&e.to_string()))
+        }
+
+        /// The UUID bytes, as a `Uint8Array`.
+        #[wasm_bindgen(js_name = toBytes)]
+        pub fn to_bytes(&self) -> Vec<u8> {
+            self.0.to_bytes().to_vec()
+        }
+
+        /// The UUID as a hyphenated lowercase string.
+        #[wasm_bindgen(js_name = toString)]
+        #[allow(clippy::inherent_to_string)]
+        pub fn to_string(&self) -> String {
+            let mut buf = [0; 36];
+            self.0.to_str(&mut buf).into()
+        }
+    }
+
+    impl From<JsUuid> for Uuid {
+        #[inline]
+        fn from(uuid: JsUuid) -> Self {
+            uuid.0
+        }
+    }
+
+    impl<S: crate::state::State> From<Uuid<S>> for JsUuid {
+        #[inline]
+        fn from(uuid: Uuid<S>) -> Self {
+            Self(uuid.erase())
+        }
+    }
+}